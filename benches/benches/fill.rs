@@ -0,0 +1,39 @@
+#[macro_use]
+extern crate criterion;
+extern crate generic_array;
+
+use criterion::{black_box, Criterion};
+use generic_array::GenericArray;
+use generic_array::typenum::U4096;
+
+fn fill(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fill u8 x 4096");
+
+    group.bench_function("GenericArray::fill", |b| {
+        let mut a = GenericArray::<u8, U4096>::default();
+
+        b.iter(|| {
+            a.fill(black_box(0xAB));
+            black_box(&a);
+        })
+    });
+
+    group.bench_function("manual loop", |b| {
+        let mut a = GenericArray::<u8, U4096>::default();
+
+        b.iter(|| {
+            let value = black_box(0xAB);
+
+            for i in 0..a.len() {
+                a[i] = value;
+            }
+
+            black_box(&a);
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, fill);
+criterion_main!(benches);
@@ -0,0 +1,39 @@
+#[macro_use]
+extern crate criterion;
+extern crate generic_array;
+
+use criterion::{black_box, Criterion, Throughput};
+use generic_array::GenericArray;
+use generic_array::typenum::U4096;
+
+fn naive_find(haystack: &[u8], needle: u8) -> Option<usize> {
+    for i in 0..haystack.len() {
+        if haystack[i] == needle {
+            return Some(i);
+        }
+    }
+
+    None
+}
+
+fn find_byte(c: &mut Criterion) {
+    // The needle only occurs in the last byte, so both searches scan everything.
+    let mut haystack = GenericArray::<u8, U4096>::generate(|i| (i % 251) as u8);
+    haystack[4095] = 0xFF;
+
+    let mut group = c.benchmark_group("find_byte u8 x 4096");
+    group.throughput(Throughput::Bytes(4096));
+
+    group.bench_function("GenericArray::find_byte", |b| {
+        b.iter(|| black_box(&haystack).find_byte(black_box(0xFF)))
+    });
+
+    group.bench_function("naive loop", |b| {
+        b.iter(|| naive_find(black_box(&haystack), black_box(0xFF)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, find_byte);
+criterion_main!(benches);
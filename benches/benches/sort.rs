@@ -0,0 +1,63 @@
+#[macro_use]
+extern crate criterion;
+extern crate generic_array;
+
+use criterion::{BatchSize, BenchmarkId, Criterion};
+use generic_array::GenericArray;
+use generic_array::typenum::{U16, U4, U8};
+
+fn shuffled(seed: u32, i: usize) -> u32 {
+    let mut x = seed ^ (i as u32).wrapping_mul(0x9E37_79B9);
+    x ^= x << 13;
+    x ^= x >> 17;
+    x ^= x << 5;
+    x
+}
+
+macro_rules! bench_sort_small {
+    ($group:expr, $($N:ident),*) => {$({
+        let input = GenericArray::<u32, $N>::generate(|i| shuffled(0xDEAD_BEEF, i));
+        let len = input.len();
+
+        $group.bench_with_input(
+            BenchmarkId::new("GenericArray::insertion_sort_small", len),
+            &input,
+            |b, input| {
+                b.iter_batched(
+                    || *input,
+                    |mut a| {
+                        a.insertion_sort_small();
+                        a
+                    },
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+
+        $group.bench_with_input(
+            BenchmarkId::new("slice sort_unstable", len),
+            &input,
+            |b, input| {
+                b.iter_batched(
+                    || *input,
+                    |mut a| {
+                        a.as_mut_slice().sort_unstable();
+                        a
+                    },
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    })*}
+}
+
+fn sort_small(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sort u32");
+
+    bench_sort_small!(group, U4, U8, U16);
+
+    group.finish();
+}
+
+criterion_group!(benches, sort_small);
+criterion_main!(benches);
@@ -0,0 +1,34 @@
+#[macro_use]
+extern crate criterion;
+extern crate generic_array;
+
+use criterion::{black_box, Criterion, Throughput};
+use generic_array::GenericArray;
+use generic_array::typenum::U4096;
+
+fn xor(c: &mut Criterion) {
+    let keystream = GenericArray::<u8, U4096>::generate(|i| (i * 31 + 7) as u8);
+
+    let mut group = c.benchmark_group("xor u8 x 4096");
+    group.throughput(Throughput::Bytes(4096));
+
+    group.bench_function("xor_assign", |b| {
+        let mut data = GenericArray::<u8, U4096>::generate(|i| i as u8);
+
+        b.iter(|| {
+            data.xor_assign(black_box(&keystream));
+            black_box(&data);
+        })
+    });
+
+    group.bench_function("xor", |b| {
+        let data = GenericArray::<u8, U4096>::generate(|i| i as u8);
+
+        b.iter(|| black_box(black_box(data).xor(black_box(&keystream))))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, xor);
+criterion_main!(benches);
@@ -0,0 +1,127 @@
+//! Base64 encoding and decoding of byte arrays, enabled by the `base64` feature.
+//!
+//! The standard alphabet with `=` padding is used.
+
+use {ArrayLength, GenericArray};
+use alloc::string::String;
+use core::fmt;
+
+static CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Error returned when decoding a byte array from base64 fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base64Error {
+    /// The length of the string is not a multiple of four.
+    InvalidLength,
+    /// The string contains a character which is not part of the base64 alphabet.
+    ///
+    /// This includes a `=` anywhere but in the padding at the end of the string.
+    InvalidCharacter {
+        /// The byte offset of the offending character
+        index: usize,
+    },
+    /// The string is valid base64, but does not decode to the length of the array.
+    WrongDecodedLength {
+        /// The length of the array
+        expected: usize,
+        /// The number of bytes encoded in the string
+        actual: usize,
+    },
+}
+
+impl fmt::Display for Base64Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Base64Error::InvalidLength => f.write_str("invalid base64 length"),
+            Base64Error::InvalidCharacter { index } => {
+                write!(f, "invalid base64 character at index {}", index)
+            }
+            Base64Error::WrongDecodedLength { expected, actual } => {
+                write!(f, "expected {} decoded bytes, found {}", expected, actual)
+            }
+        }
+    }
+}
+
+fn decode_char(c: u8) -> Option<u32> {
+    let v = match c {
+        b'A'..=b'Z' => c - b'A',
+        b'a'..=b'z' => c - b'a' + 26,
+        b'0'..=b'9' => c - b'0' + 52,
+        b'+' => 62,
+        b'/' => 63,
+        _ => return None,
+    };
+
+    Some(u32::from(v))
+}
+
+impl<T: ArrayLength<u8>> GenericArray<u8, T> {
+    /// Encodes the array as a padded base64 `String`.
+    pub fn to_base64(&self) -> String {
+        let mut res = String::with_capacity((T::to_usize() + 2) / 3 * 4);
+
+        for chunk in self.chunks(3) {
+            let b = [
+                chunk[0],
+                chunk.get(1).cloned().unwrap_or(0),
+                chunk.get(2).cloned().unwrap_or(0),
+            ];
+            let n = u32::from(b[0]) << 16 | u32::from(b[1]) << 8 | u32::from(b[2]);
+
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    res.push(CHARS[(n >> (18 - 6 * i) & 0x3F) as usize] as char);
+                } else {
+                    res.push('=');
+                }
+            }
+        }
+
+        res
+    }
+
+    /// Decodes a byte array from a padded base64 string.
+    ///
+    /// The string must decode to exactly `T` bytes.
+    pub fn from_base64(s: &str) -> Result<GenericArray<u8, T>, Base64Error> {
+        let chars = s.as_bytes();
+
+        if chars.len() % 4 != 0 {
+            return Err(Base64Error::InvalidLength);
+        }
+
+        let padding = chars
+            .iter()
+            .rev()
+            .take(2)
+            .take_while(|&&c| c == b'=')
+            .count();
+        let decoded_len = chars.len() / 4 * 3 - padding;
+
+        if decoded_len != T::to_usize() {
+            return Err(Base64Error::WrongDecodedLength {
+                expected: T::to_usize(),
+                actual: decoded_len,
+            });
+        }
+
+        let mut res = GenericArray::<u8, T>::default();
+
+        for (i, chunk) in chars[..chars.len() - padding].chunks(4).enumerate() {
+            let mut n = 0;
+
+            for (j, &c) in chunk.iter().enumerate() {
+                let v = decode_char(c).ok_or(Base64Error::InvalidCharacter { index: i * 4 + j })?;
+
+                n |= v << (18 - 6 * j);
+            }
+
+            for (j, dst) in res[i * 3..].iter_mut().take(chunk.len() - 1).enumerate() {
+                *dst = (n >> (16 - 8 * j)) as u8;
+            }
+        }
+
+        Ok(res)
+    }
+}
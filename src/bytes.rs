@@ -0,0 +1,181 @@
+//! Helpers for `GenericArray`s of bytes.
+
+use {ArrayLength, AsciiError, GenericArray};
+use core::mem;
+use core::ops::{Add, Mul, Sub};
+use core::str;
+use typenum::bit::B1;
+use typenum::consts::{U256, U4, U8};
+use typenum::operator_aliases::{Add1, Diff, Prod};
+use typenum::Unsigned;
+#[cfg(feature = "std")]
+use std::io::{self, Read, Write};
+
+impl<N> GenericArray<u8, N>
+where
+    N: ArrayLength<u8>,
+{
+    /// Views the array as a string slice, if it contains valid UTF-8.
+    #[inline]
+    pub fn as_str(&self) -> Result<&str, str::Utf8Error> {
+        str::from_utf8(self)
+    }
+
+    /// Views the array as a string slice, if all bytes are ASCII.
+    ///
+    /// This is cheaper than full UTF-8 validation.
+    pub fn as_ascii_str(&self) -> Result<&str, AsciiError> {
+        match self.iter().position(|b| !b.is_ascii()) {
+            Some(index) => Err(AsciiError::new(index)),
+            None => Ok(unsafe { str::from_utf8_unchecked(self) }),
+        }
+    }
+
+    /// Returns the index of the first occurrence of `needle` in the array.
+    ///
+    /// With the `memchr` feature enabled this uses the `memchr` crate's vectorized search.
+    #[inline]
+    pub fn find_byte(&self, needle: u8) -> Option<usize> {
+        #[cfg(feature = "memchr")]
+        {
+            ::memchr::memchr(needle, self)
+        }
+
+        #[cfg(not(feature = "memchr"))]
+        {
+            self.iter().position(|&b| b == needle)
+        }
+    }
+
+    /// XORs every byte of the array with the corresponding byte of `keystream`, in place.
+    #[inline]
+    pub fn xor_assign(&mut self, keystream: &GenericArray<u8, N>) {
+        for (a, &k) in self.iter_mut().zip(keystream.iter()) {
+            *a ^= k;
+        }
+    }
+
+    /// XORs every byte of the array with the corresponding byte of `keystream`.
+    #[inline]
+    pub fn xor(mut self, keystream: &GenericArray<u8, N>) -> Self {
+        self.xor_assign(keystream);
+        self
+    }
+
+    /// Replaces every byte `b` of the array with `table[b]`, e.g. for an S-box.
+    ///
+    /// Since the table covers every possible byte, the lookups need no bounds checks.
+    #[inline]
+    pub fn apply_lut<T>(self, table: &GenericArray<T, U256>) -> GenericArray<T, N>
+    where
+        T: Copy,
+        N: ArrayLength<T>,
+    {
+        self.map(|b| table[b as usize])
+    }
+
+    /// Computes the polynomial hash of every window of `W` consecutive bytes,
+    /// `(s[0] * base^(W - 1) + s[1] * base^(W - 2) + ... + s[W - 1]) % modulus`.
+    ///
+    /// Each hash is derived from the previous one in constant time,
+    /// rather than by rehashing the whole window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn rolling_hashes<W>(&self, base: u64, modulus: u64) -> GenericArray<u64, Add1<Diff<N, W>>>
+    where
+        W: Unsigned,
+        N: Sub<W>,
+        Diff<N, W>: Add<B1>,
+        Add1<Diff<N, W>>: ArrayLength<u64>,
+    {
+        assert!(modulus > 0, "rolling_hashes modulus must not be zero");
+
+        // Every intermediate value is less than `modulus` before being multiplied,
+        // so the products fit in a `u128`.
+        let m = u128::from(modulus);
+        let base = u128::from(base) % m;
+        let window = W::to_usize();
+
+        // The weight of the byte leaving the window, `base^(W - 1)`.
+        let weight = (1..window).fold(1 % m, |acc, _| acc * base % m);
+
+        let mut hash = self[..window]
+            .iter()
+            .fold(0, |acc, &b| (acc * base + u128::from(b)) % m);
+
+        GenericArray::generate(|i| {
+            if i > 0 && window > 0 {
+                let outgoing = u128::from(self[i - 1]) * weight % m;
+                let incoming = u128::from(self[i + window - 1]);
+
+                hash = ((hash + m - outgoing) % m * base + incoming) % m;
+            }
+
+            hash as u64
+        })
+    }
+
+    /// Reads exactly `N` bytes from `reader` into a new array.
+    ///
+    /// Fails with `io::ErrorKind::UnexpectedEof` if the reader ends early.
+    #[cfg(feature = "std")]
+    pub fn read_from<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut array = Self::default();
+
+        reader.read_exact(&mut array)?;
+
+        Ok(array)
+    }
+
+    /// Writes all bytes of the array to `writer`.
+    #[cfg(feature = "std")]
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(self)
+    }
+}
+
+macro_rules! impl_be_bytes {
+    ($($ty: ty => $size: ty),*) => {
+        $(
+            impl<N> GenericArray<$ty, N>
+            where
+                N: ArrayLength<$ty>,
+            {
+                /// Serializes the array into the concatenated big-endian bytes of its elements.
+                pub fn to_be_bytes(&self) -> GenericArray<u8, Prod<N, $size>>
+                where
+                    N: Mul<$size>,
+                    Prod<N, $size>: ArrayLength<u8>,
+                {
+                    let mut bytes = GenericArray::<u8, Prod<N, $size>>::default();
+
+                    for (dst, x) in bytes.chunks_mut(mem::size_of::<$ty>()).zip(self.iter()) {
+                        dst.copy_from_slice(&x.to_be_bytes());
+                    }
+
+                    bytes
+                }
+
+                /// Deserializes an array from the concatenated big-endian bytes of its elements.
+                pub fn from_be_bytes(bytes: &GenericArray<u8, Prod<N, $size>>) -> Self
+                where
+                    N: Mul<$size>,
+                    Prod<N, $size>: ArrayLength<u8>,
+                {
+                    const SIZE: usize = mem::size_of::<$ty>();
+
+                    GenericArray::generate(|i| {
+                        let mut element = [0; SIZE];
+                        element.copy_from_slice(&bytes[i * SIZE..(i + 1) * SIZE]);
+
+                        <$ty>::from_be_bytes(element)
+                    })
+                }
+            }
+        )*
+    }
+}
+
+impl_be_bytes!(u32 => U4, u64 => U8);
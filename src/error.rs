@@ -0,0 +1,203 @@
+//! Error types returned by fallible `GenericArray` operations.
+
+use core::fmt;
+
+/// Error returned by `GenericArray::scatter` when the target indices are invalid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScatterError {
+    /// The target index is out of bounds of the array.
+    OutOfBounds {
+        /// The offending target index
+        index: usize,
+    },
+    /// The target index appears more than once.
+    Duplicate {
+        /// The offending target index
+        index: usize,
+    },
+}
+
+impl fmt::Display for ScatterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ScatterError::OutOfBounds { index } => {
+                write!(f, "scatter index {} is out of bounds", index)
+            }
+            ScatterError::Duplicate { index } => write!(f, "scatter index {} is duplicated", index),
+        }
+    }
+}
+
+/// Error returned by `GenericArray::permute_in_place` when the permutation is invalid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PermutationError {
+    /// The source index is out of bounds of the array.
+    OutOfBounds {
+        /// The offending source index
+        index: usize,
+    },
+    /// The source index appears more than once.
+    Duplicate {
+        /// The offending source index
+        index: usize,
+    },
+}
+
+impl fmt::Display for PermutationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PermutationError::OutOfBounds { index } => {
+                write!(f, "permutation index {} is out of bounds", index)
+            }
+            PermutationError::Duplicate { index } => {
+                write!(f, "permutation index {} is duplicated", index)
+            }
+        }
+    }
+}
+
+/// Error returned when a byte array is expected to contain only ASCII characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AsciiError {
+    index: usize,
+}
+
+impl AsciiError {
+    pub(crate) fn new(index: usize) -> AsciiError {
+        AsciiError { index }
+    }
+
+    /// Returns the index of the first non-ASCII byte.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for AsciiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "non-ASCII byte at index {}", self.index)
+    }
+}
+
+/// Error returned when a length does not match the one which was expected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LengthError {
+    expected: usize,
+    actual: usize,
+}
+
+impl LengthError {
+    pub(crate) fn new(expected: usize, actual: usize) -> LengthError {
+        LengthError { expected, actual }
+    }
+
+    /// Returns the expected length.
+    pub fn expected(&self) -> usize {
+        self.expected
+    }
+
+    /// Returns the actual length.
+    pub fn actual(&self) -> usize {
+        self.actual
+    }
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "expected length {}, found {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+/// Error returned by `GenericArray::try_zip_slices` when one of the slices has the wrong length.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZipLengthError {
+    /// The first slice has the wrong length.
+    First(LengthError),
+    /// The second slice has the wrong length.
+    Second(LengthError),
+}
+
+impl fmt::Display for ZipLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ZipLengthError::First(ref e) => write!(f, "first slice: {}", e),
+            ZipLengthError::Second(ref e) => write!(f, "second slice: {}", e),
+        }
+    }
+}
+
+/// Error returned when an element-wise arithmetic operation overflows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OverflowError {
+    index: usize,
+}
+
+impl OverflowError {
+    pub(crate) fn new(index: usize) -> OverflowError {
+        OverflowError { index }
+    }
+
+    /// Returns the index of the first element which overflowed.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "arithmetic overflow at index {}", self.index)
+    }
+}
+
+/// Error returned by `GenericArray::try_from_results`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TryCollectError<E> {
+    /// The iterator yielded an error.
+    Item(E),
+    /// The iterator ended after yielding only the given number of values.
+    TooShort(usize),
+}
+
+impl<E: fmt::Display> fmt::Display for TryCollectError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TryCollectError::Item(ref e) => e.fmt(f),
+            TryCollectError::TooShort(got) => write!(f, "iterator ended after {} items", got),
+        }
+    }
+}
+
+/// Error returned by `GenericArray::from_iter_exact` when the iterator does not
+/// yield exactly the length of the array.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExactLenError {
+    /// The iterator ended early.
+    TooFew {
+        /// The number of values the iterator yielded
+        got: usize,
+        /// The length of the array
+        expected: usize,
+    },
+    /// The iterator yielded more values than the length of the array.
+    TooMany {
+        /// The length of the array
+        expected: usize,
+    },
+}
+
+impl fmt::Display for ExactLenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ExactLenError::TooFew { got, expected } => {
+                write!(f, "iterator yielded {} items, expected {}", got, expected)
+            }
+            ExactLenError::TooMany { expected } => {
+                write!(f, "iterator yielded more than {} items", expected)
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Generic array are commonly used as a return value for hash digests, so
 //! it's a good idea to allow to hexlify them easily. This module implements
-//! `std::fmt::LowerHex` and `std::fmt::UpperHex` traits.
+//! `std::fmt::LowerHex` and `std::fmt::UpperHex` traits, as well as parsing
+//! byte arrays back from hex strings.
 //!
 //! Example:
 //!
@@ -16,6 +17,8 @@
 //!
 
 use {ArrayLength, GenericArray};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 use core::fmt;
 use core::ops::Add;
 use core::str;
@@ -91,3 +94,102 @@ where
         Ok(())
     }
 }
+
+/// Wrapper around a byte array whose `Debug` implementation renders
+/// the bytes as compact lowercase hex, created by `GenericArray::hex_debug`.
+pub struct HexDebug<'a, T: 'a + ArrayLength<u8>>(&'a GenericArray<u8, T>);
+
+impl<'a, T: ArrayLength<u8>> fmt::Debug for HexDebug<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for c in self.0.iter() {
+            write!(f, "{:02x}", c)?;
+        }
+        Ok(())
+    }
+}
+
+/// Error returned when parsing a byte array from a hex string fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HexError {
+    /// The string does not consist of exactly two hex digits per byte.
+    InvalidLength {
+        /// The expected length of the string
+        expected: usize,
+        /// The actual length of the string
+        actual: usize,
+    },
+    /// The string contains a character which is not a hex digit.
+    InvalidCharacter {
+        /// The byte offset of the offending character
+        index: usize,
+    },
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            HexError::InvalidLength { expected, actual } => {
+                write!(f, "expected {} hex digits, found {}", expected, actual)
+            }
+            HexError::InvalidCharacter { index } => {
+                write!(f, "invalid hex digit at index {}", index)
+            }
+        }
+    }
+}
+
+fn hex_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+impl<T: ArrayLength<u8>> GenericArray<u8, T> {
+    /// Returns a wrapper whose `Debug` output is the array as compact lowercase hex,
+    /// instead of a list of decimal numbers.
+    #[inline]
+    pub fn hex_debug(&self) -> HexDebug<'_, T> {
+        HexDebug(self)
+    }
+
+    /// Parses a byte array from a string of exactly two hex digits per byte.
+    ///
+    /// Both lowercase and uppercase digits are accepted.
+    pub fn from_hex(s: &str) -> Result<GenericArray<u8, T>, HexError> {
+        let digits = s.as_bytes();
+
+        if digits.len() != T::to_usize() * 2 {
+            return Err(HexError::InvalidLength {
+                expected: T::to_usize() * 2,
+                actual: digits.len(),
+            });
+        }
+
+        let mut res = GenericArray::<u8, T>::default();
+
+        for (i, pair) in digits.chunks(2).enumerate() {
+            let hi = hex_value(pair[0]).ok_or(HexError::InvalidCharacter { index: i * 2 })?;
+            let lo = hex_value(pair[1]).ok_or(HexError::InvalidCharacter { index: i * 2 + 1 })?;
+
+            res[i] = hi << 4 | lo;
+        }
+
+        Ok(res)
+    }
+
+    /// Renders the array as a `String` of lowercase hex digits, two per byte.
+    #[cfg(feature = "alloc")]
+    pub fn to_hex(&self) -> String {
+        let mut res = String::with_capacity(T::to_usize() * 2);
+
+        for c in self.iter() {
+            res.push(LOWER_CHARS[(c >> 4) as usize] as char);
+            res.push(LOWER_CHARS[(c & 0xF) as usize] as char);
+        }
+
+        res
+    }
+}
@@ -0,0 +1,122 @@
+//! Functionality which requires a heap allocator, enabled by the `alloc` feature.
+
+use {ArrayBuilder, ArrayLength, GenericArray};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::ptr;
+
+impl<T, N> GenericArray<T, N>
+where
+    N: ArrayLength<T>,
+{
+    /// Maps a `GenericArray` to another `GenericArray` with a fallible function.
+    ///
+    /// Unlike a short-circuiting map, `f` is called on every element, and all errors
+    /// are collected in order. The output array is only produced if every call succeeds,
+    /// otherwise any successfully mapped values are dropped.
+    pub fn map_collect_errors<U, E, F>(self, mut f: F) -> Result<GenericArray<U, N>, Vec<E>>
+    where
+        F: FnMut(T) -> Result<U, E>,
+        N: ArrayLength<U>,
+    {
+        let mut destination = ArrayBuilder::new();
+        let mut errors = Vec::new();
+
+        for src in self {
+            match f(src) {
+                Ok(value) => {
+                    if errors.is_empty() {
+                        unsafe {
+                            destination.push(value);
+                        }
+                    }
+                }
+                Err(e) => errors.push(e),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(destination.into_inner())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Moves the elements of a boxed slice into a new array, freeing the box.
+    ///
+    /// Returns the box unchanged if its length is not equal to the length of the array.
+    pub fn from_boxed_slice(b: Box<[T]>) -> Result<GenericArray<T, N>, Box<[T]>> {
+        // A `Vec` made from a boxed slice has no spare capacity,
+        // so turning it back into a box does not reallocate.
+        Self::from_vec(b.into_vec()).map_err(Vec::into_boxed_slice)
+    }
+
+    /// Moves the elements of a `Vec` into a new array, freeing the `Vec`.
+    ///
+    /// Returns the `Vec` unchanged if its length is not equal to the length of the array.
+    pub fn from_vec(mut v: Vec<T>) -> Result<GenericArray<T, N>, Vec<T>> {
+        if v.len() != N::to_usize() {
+            return Err(v);
+        }
+
+        unsafe {
+            let array = ptr::read(v.as_ptr() as *const GenericArray<T, N>);
+
+            // The elements now belong to `array`, so only the allocation is freed.
+            v.set_len(0);
+
+            Ok(array)
+        }
+    }
+
+    /// Moves all elements of the array into a new `Vec`.
+    pub fn into_vec(self) -> Vec<T> {
+        let mut v = Vec::with_capacity(N::to_usize());
+
+        v.extend(self);
+
+        v
+    }
+
+    /// Collects the indices of all elements satisfying the predicate, in order.
+    pub fn positions<P>(&self, mut predicate: P) -> Vec<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .enumerate()
+            .filter(|&(_, x)| predicate(x))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Moves the elements of the array into a `Vec`, dropping those which are
+    /// equal to the element directly before them.
+    pub fn dedup_to_vec(self) -> Vec<T>
+    where
+        T: PartialEq,
+    {
+        let mut v: Vec<T> = Vec::with_capacity(N::to_usize());
+
+        for x in self {
+            if v.last() != Some(&x) {
+                v.push(x);
+            }
+        }
+
+        v
+    }
+
+    /// Concatenates the array with a slice of runtime length, cloning both into a `Vec`.
+    pub fn concat_with_slice(&self, rest: &[T]) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut v = Vec::with_capacity(N::to_usize() + rest.len());
+
+        v.extend_from_slice(self);
+        v.extend_from_slice(rest);
+
+        v
+    }
+}
@@ -0,0 +1,31 @@
+//! Parallel operations using `rayon`, enabled by the `rayon` feature.
+
+use {ArrayLength, GenericArray};
+use alloc::vec::Vec;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+impl<T, N> GenericArray<T, N>
+where
+    N: ArrayLength<T>,
+{
+    /// Reduces the elements of the array with `f` in parallel on the `rayon` thread pool.
+    ///
+    /// `f` must be associative, as the elements are combined in an unspecified grouping,
+    /// though always in their original order. An empty array reduces to `identity`.
+    ///
+    /// The elements are first moved into a `Vec` of length `N`, which is what rayon
+    /// splits between threads. rayon can only hand out owned elements from its own
+    /// collection types, and the array cannot give up ownership of its elements
+    /// piecemeal without that, so this allocates once per call.
+    pub fn par_reduce<F>(self, identity: T, f: F) -> T
+    where
+        T: Send,
+        F: Fn(T, T) -> T + Sync + Send,
+    {
+        let mut elements = Vec::with_capacity(N::to_usize());
+
+        elements.extend(self);
+
+        elements.into_par_iter().reduce_with(f).unwrap_or(identity)
+    }
+}
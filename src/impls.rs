@@ -130,6 +130,15 @@ macro_rules! impl_from {
                     x
                 }
             }
+
+            impl<T> From<GenericArray<T, $ty>> for [T; $n] {
+                fn from(arr: GenericArray<T, $ty>) -> Self {
+                    use core::mem::{forget, transmute_copy};
+                    let x = unsafe { transmute_copy(&arr) };
+                    forget(arr);
+                    x
+                }
+            }
         )*
 
     }
@@ -39,29 +39,59 @@
 #![deny(missing_docs)]
 #![no_std]
 
+#[cfg(any(feature = "alloc", feature = "rayon"))]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "memchr")]
+extern crate memchr;
+#[cfg(feature = "rayon")]
+extern crate rayon;
 #[cfg(feature = "serde")]
 extern crate serde;
 pub extern crate typenum;
 
+mod bytes;
+mod error;
 mod hex;
 mod impls;
-
+mod matrix;
+mod numeric;
+
+#[cfg(feature = "base64")]
+mod base64;
+#[cfg(feature = "alloc")]
+mod impl_alloc;
+#[cfg(feature = "rayon")]
+mod impl_rayon;
 #[cfg(feature = "serde")]
 pub mod impl_serde;
 
 use core::{mem, ptr, slice};
+use core::cmp::Ordering;
 use core::marker::PhantomData;
-use core::mem::ManuallyDrop;
+use core::mem::{ManuallyDrop, MaybeUninit};
 use core::ops::{Deref, DerefMut};
 use typenum::bit::{B0, B1};
+use typenum::marker_traits::{NonZero, PowerOfTwo};
 use typenum::uint::{UInt, UTerm, Unsigned};
 
 #[cfg_attr(test, macro_use)]
 pub mod arr;
 pub mod iter;
 pub mod sequence;
-
+pub mod swizzle;
+pub mod tuple;
+
+#[cfg(feature = "base64")]
+pub use base64::Base64Error;
+pub use error::{
+    AsciiError, ExactLenError, LengthError, OverflowError, PermutationError, ScatterError,
+    TryCollectError, ZipLengthError,
+};
+pub use hex::{HexDebug, HexError};
 pub use iter::GenericArrayIter;
+pub use numeric::SaturatingFrom;
 
 /// Trait making `GenericArray` work, marking types to be used as length of an array
 pub unsafe trait ArrayLength<T>: Unsigned {
@@ -70,10 +100,19 @@ pub unsafe trait ArrayLength<T>: Unsigned {
 }
 
 unsafe impl<T> ArrayLength<T> for UTerm {
+    // Zero-sized, but keeps the alignment of `T` so empty arrays deref to valid slices
     #[doc(hidden)]
-    type ArrayType = ();
+    type ArrayType = [T; 0];
 }
 
+/// Marker trait implemented by `()` only when `A` and `B` are the same length,
+/// for requiring otherwise unrelated arrays to be equally long in generic code.
+///
+/// See `GenericArray::assert_same_length`.
+pub trait SameLength<A, B> {}
+
+impl<N: Unsigned> SameLength<N, N> for () {}
+
 /// Internal type used to generate a struct of appropriate size
 #[allow(dead_code)]
 #[repr(C)]
@@ -155,33 +194,48 @@ where
 }
 
 struct ArrayBuilder<T, N: ArrayLength<T>> {
-    array: ManuallyDrop<GenericArray<T, N>>,
+    array: MaybeUninit<GenericArray<T, N>>,
     position: usize,
 }
 
 impl<T, N: ArrayLength<T>> ArrayBuilder<T, N> {
     fn new() -> ArrayBuilder<T, N> {
         ArrayBuilder {
-            array: ManuallyDrop::new(unsafe { mem::uninitialized() }),
+            array: MaybeUninit::uninit(),
             position: 0,
         }
     }
 
+    /// Writes the next element of the array.
+    ///
+    /// Must not be called more than `N` times.
+    unsafe fn push(&mut self, value: T) {
+        ptr::write(
+            (self.array.as_mut_ptr() as *mut T).add(self.position),
+            value,
+        );
+
+        self.position += 1;
+    }
+
     fn into_inner(self) -> GenericArray<T, N> {
+        assert_eq!(self.position, N::to_usize());
+
         let array = unsafe { ptr::read(&self.array) };
 
         mem::forget(self);
 
-        ManuallyDrop::into_inner(array)
+        unsafe { array.assume_init() }
     }
 }
 
 impl<T, N: ArrayLength<T>> Drop for ArrayBuilder<T, N> {
     fn drop(&mut self) {
-        for value in self.array.iter_mut().take(self.position) {
-            unsafe {
-                ptr::drop_in_place(value);
-            }
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                self.array.as_mut_ptr() as *mut T,
+                self.position,
+            ));
         }
     }
 }
@@ -218,23 +272,37 @@ where
     ///
     /// If the generator function panics while initializing the array,
     /// any already initialized elements will be dropped.
-    pub fn generate<F>(f: F) -> GenericArray<T, N>
+    pub fn generate<F>(mut f: F) -> GenericArray<T, N>
     where
-        F: Fn(usize) -> T,
+        F: FnMut(usize) -> T,
     {
         let mut destination = ArrayBuilder::new();
 
-        for (i, dst) in destination.array.iter_mut().enumerate() {
+        for i in 0..N::to_usize() {
             unsafe {
-                ptr::write(dst, f(i));
+                destination.push(f(i));
             }
-
-            destination.position += 1;
         }
 
         destination.into_inner()
     }
 
+    /// Initializes a new `GenericArray` instance using the given function,
+    /// after checking that the array has the expected length.
+    ///
+    /// Returns an error whose actual length is `N`, without calling `f`,
+    /// if `N` is not equal to `expected_len`.
+    pub fn from_fn_checked<F>(expected_len: usize, f: F) -> Result<GenericArray<T, N>, LengthError>
+    where
+        F: FnMut(usize) -> T,
+    {
+        if N::to_usize() == expected_len {
+            Ok(Self::generate(f))
+        } else {
+            Err(LengthError::new(expected_len, N::to_usize()))
+        }
+    }
+
     /// Map a function over a slice to a `GenericArray`.
     ///
     /// The length of the slice *must* be equal to the length of the array.
@@ -257,13 +325,14 @@ where
         let mut source = ArrayConsumer::new(self);
         let mut destination = ArrayBuilder::new();
 
-        for (dst, src) in destination.array.iter_mut().zip(source.array.iter()) {
+        for i in 0..N::to_usize() {
             unsafe {
-                ptr::write(dst, f(ptr::read(src)));
-            }
+                let src = ptr::read(source.array.get_unchecked(i));
+
+                source.position += 1;
 
-            source.position += 1;
-            destination.position += 1;
+                destination.push(f(src));
+            }
         }
 
         destination.into_inner()
@@ -281,6 +350,56 @@ where
         GenericArray::generate(|i| f(unsafe { self.get_unchecked(i) }))
     }
 
+    /// Maps a `GenericArray` to another `GenericArray` with a fallible function
+    /// which is also given the index of each element.
+    ///
+    /// Stops at the first error, dropping any already initialized elements in the new array
+    /// AND any unused elements in the source array.
+    pub fn try_map_with_index<U, E, F>(self, mut f: F) -> Result<GenericArray<U, N>, E>
+    where
+        F: FnMut(usize, T) -> Result<U, E>,
+        N: ArrayLength<U>,
+    {
+        let mut source = ArrayConsumer::new(self);
+        let mut destination = ArrayBuilder::new();
+
+        for i in 0..N::to_usize() {
+            unsafe {
+                let src = ptr::read(source.array.get_unchecked(i));
+
+                source.position += 1;
+
+                destination.push(f(i, src)?);
+            }
+        }
+
+        Ok(destination.into_inner())
+    }
+
+    /// Folds every element into an accumulator with a fallible function, consuming the array.
+    ///
+    /// Stops at the first error, dropping any elements which were not yet consumed.
+    #[inline]
+    pub fn try_fold<B, E, F>(self, init: B, f: F) -> Result<B, E>
+    where
+        F: FnMut(B, T) -> Result<B, E>,
+    {
+        self.into_iter().try_fold(init, f)
+    }
+
+    /// Folds every element into an accumulator, using the first element as the initial value.
+    ///
+    /// Returns `None` if the array is empty.
+    #[inline]
+    pub fn fold_first<F>(self, f: F) -> Option<T>
+    where
+        F: FnMut(T, T) -> T,
+    {
+        let mut iter = self.into_iter();
+
+        iter.next().map(|first| iter.fold(first, f))
+    }
+
     /// Combines two `GenericArray` instances and iterates through both of them,
     /// initializing a new `GenericArray` with the result of the zipped mapping function.
     ///
@@ -296,18 +415,16 @@ where
 
         let mut destination = ArrayBuilder::new();
 
-        for (dst, (lhs, rhs)) in destination
-            .array
-            .iter_mut()
-            .zip(left.array.iter().zip(right.array.iter()))
-        {
+        for i in 0..N::to_usize() {
             unsafe {
-                ptr::write(dst, f(ptr::read(lhs), ptr::read(rhs)));
-            }
+                let lhs = ptr::read(left.array.get_unchecked(i));
+                let rhs = ptr::read(right.array.get_unchecked(i));
 
-            destination.position += 1;
-            left.position += 1;
-            right.position += 1;
+                left.position += 1;
+                right.position += 1;
+
+                destination.push(f(lhs, rhs));
+            }
         }
 
         destination.into_inner()
@@ -325,6 +442,125 @@ where
         GenericArray::generate(|i| unsafe { f(self.get_unchecked(i), rhs.get_unchecked(i)) })
     }
 
+    /// Combines the array with a slice by reference, initializing a new `GenericArray`
+    /// with the result of the zipped mapping function.
+    ///
+    /// Returns `None` if the length of the slice is not equal to the length of the array.
+    pub fn zip_slice<B, U, F>(&self, rhs: &[B], mut f: F) -> Option<GenericArray<U, N>>
+    where
+        F: FnMut(&T, &B) -> U,
+        N: ArrayLength<U>,
+    {
+        if rhs.len() != N::to_usize() {
+            return None;
+        }
+
+        Some(GenericArray::generate(|i| unsafe {
+            f(self.get_unchecked(i), rhs.get_unchecked(i))
+        }))
+    }
+
+    /// Combines the array with a slice by reference, initializing a new `GenericArray`
+    /// with the result of the zipped mapping function.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the message `"slice length {actual} != array length {N}"`
+    /// if the length of the slice is not equal to the length of the array.
+    pub fn zip_slice_eq<B, U, F>(&self, rhs: &[B], f: F) -> GenericArray<U, N>
+    where
+        F: FnMut(&T, &B) -> U,
+        N: ArrayLength<U>,
+    {
+        match self.zip_slice(rhs, f) {
+            Some(array) => array,
+            None => panic!(
+                "slice length {} != array length {}",
+                rhs.len(),
+                N::to_usize()
+            ),
+        }
+    }
+
+    /// Combines two slices of length `N` element-wise, initializing a new `GenericArray`
+    /// with the result of the zipped mapping function.
+    ///
+    /// Returns an error naming the first slice whose length is not equal to `N`.
+    pub fn try_zip_slices<U, V, F>(
+        a: &[T],
+        b: &[U],
+        mut f: F,
+    ) -> Result<GenericArray<V, N>, ZipLengthError>
+    where
+        F: FnMut(&T, &U) -> V,
+        N: ArrayLength<V>,
+    {
+        let len = N::to_usize();
+
+        if a.len() != len {
+            return Err(ZipLengthError::First(LengthError::new(len, a.len())));
+        }
+        if b.len() != len {
+            return Err(ZipLengthError::Second(LengthError::new(len, b.len())));
+        }
+
+        Ok(GenericArray::generate(|i| unsafe {
+            f(a.get_unchecked(i), b.get_unchecked(i))
+        }))
+    }
+
+    /// Does nothing, but only compiles if both arrays have the same length.
+    ///
+    /// Calling it with arrays of different lengths fails to compile. Because the
+    /// only implementation of `SameLength` is for equal lengths, the compiler
+    /// reports this as a mismatch between the two array types:
+    ///
+    /// ```compile_fail,E0308
+    /// # #[macro_use]
+    /// # extern crate generic_array;
+    /// # use generic_array::GenericArray;
+    /// # use generic_array::typenum::{U2, U3};
+    /// # fn main() {
+    /// let a: GenericArray<i32, U2> = arr![i32; 1, 2];
+    /// let b: GenericArray<u8, U3> = arr![u8; 1, 2, 3];
+    ///
+    /// GenericArray::assert_same_length(&a, &b);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn assert_same_length<U, M>(_a: &GenericArray<T, N>, _b: &GenericArray<U, M>)
+    where
+        M: ArrayLength<U>,
+        (): SameLength<N, M>,
+    {
+    }
+
+    /// Selects elements from two arrays according to a mask, taking `if_true[i]`
+    /// where `mask[i]` is `true` and `if_false[i]` otherwise.
+    ///
+    /// The elements which were not selected are dropped.
+    pub fn select(
+        mask: &GenericArray<bool, N>,
+        if_true: GenericArray<T, N>,
+        if_false: GenericArray<T, N>,
+    ) -> GenericArray<T, N>
+    where
+        N: ArrayLength<bool>,
+    {
+        let mut if_true = if_true.into_iter();
+        let mut if_false = if_false.into_iter();
+
+        GenericArray::generate(|i| {
+            let (t, f) = (if_true.next().unwrap(), if_false.next().unwrap());
+
+            if mask[i] {
+                t
+            } else {
+                f
+            }
+        })
+    }
+
     /// Extracts a slice containing the entire array.
     #[inline]
     pub fn as_slice(&self) -> &[T] {
@@ -337,6 +573,69 @@ where
         self.deref_mut()
     }
 
+    /// Returns the array rotated left by `k` positions, wrapping `k` around the length.
+    #[inline]
+    pub fn rotated_left(mut self, k: usize) -> GenericArray<T, N> {
+        if N::to_usize() > 0 {
+            self.rotate_left(k % N::to_usize());
+        }
+
+        self
+    }
+
+    /// Splits the array at `head` into the slices `[head, N)` and `[0, head)`, in that order,
+    /// so that walking them one after the other visits a ring buffer starting at `head`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `head > N`.
+    #[inline]
+    pub fn split_at_cycled(&self, head: usize) -> (&[T], &[T]) {
+        let (front, back) = self.split_at(head);
+
+        (back, front)
+    }
+
+    /// Swaps all elements of the array with those of a slice.
+    ///
+    /// Length of the slice must be equal to the length of the array.
+    #[inline]
+    pub fn swap_with_slice(&mut self, other: &mut [T]) {
+        assert_eq!(
+            other.len(),
+            N::to_usize(),
+            "slice length {} != array length {}",
+            other.len(),
+            N::to_usize()
+        );
+
+        self.as_mut_slice().swap_with_slice(other)
+    }
+
+    /// Moves the elements of `value` into `self[start..start + K]`,
+    /// dropping the overwritten elements.
+    ///
+    /// Returns `value` unchanged if it does not fit inside the array at `start`.
+    pub fn write_sub<K>(
+        &mut self,
+        start: usize,
+        value: GenericArray<T, K>,
+    ) -> Result<(), GenericArray<T, K>>
+    where
+        K: ArrayLength<T>,
+    {
+        let target = match start.checked_add(K::to_usize()) {
+            Some(end) if end <= N::to_usize() => &mut self[start..end],
+            _ => return Err(value),
+        };
+
+        for (dst, src) in target.iter_mut().zip(value) {
+            *dst = src;
+        }
+
+        Ok(())
+    }
+
     /// Converts slice to a generic array reference with inferred length;
     ///
     /// Length of the slice must be equal to the length of the array.
@@ -356,6 +655,439 @@ where
 
         unsafe { &mut *(slice.as_mut_ptr() as *mut GenericArray<T, N>) }
     }
+
+    /// Returns a reference to the first element of the array, or `None` if it is empty.
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        self.as_slice().first()
+    }
+
+    /// Returns a mutable reference to the first element of the array, or `None` if it is empty.
+    #[inline]
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.as_mut_slice().first_mut()
+    }
+
+    /// Returns a reference to the last element of the array, or `None` if it is empty.
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        self.as_slice().last()
+    }
+
+    /// Returns a mutable reference to the last element of the array, or `None` if it is empty.
+    #[inline]
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.as_mut_slice().last_mut()
+    }
+
+    /// Returns a reference to the first element of an array which is statically known
+    /// to be non-empty.
+    #[inline]
+    pub fn first_typed(&self) -> &T
+    where
+        N: NonZero,
+    {
+        unsafe { self.get_unchecked(0) }
+    }
+
+    /// Returns an iterator over the elements of the array along with their indices.
+    #[inline]
+    pub fn enumerate(&self) -> ::core::iter::Enumerate<slice::Iter<'_, T>> {
+        self.as_slice().iter().enumerate()
+    }
+
+    /// Returns an iterator over mutable references to the elements of the array
+    /// along with their indices.
+    #[inline]
+    pub fn enumerate_mut(&mut self) -> ::core::iter::Enumerate<slice::IterMut<'_, T>> {
+        self.as_mut_slice().iter_mut().enumerate()
+    }
+
+    /// Returns an iterator over the elements of the array, starting from the last.
+    ///
+    /// Unlike `iter().rev()`, this cannot be shadowed by a `rev` method on a trait in scope.
+    #[inline]
+    pub fn iter_rev(&self) -> ::core::iter::Rev<slice::Iter<'_, T>> {
+        self.as_slice().iter().rev()
+    }
+
+    /// Returns an iterator over mutable references to the elements of the array,
+    /// starting from the last.
+    #[inline]
+    pub fn iter_rev_mut(&mut self) -> ::core::iter::Rev<slice::IterMut<'_, T>> {
+        self.as_mut_slice().iter_mut().rev()
+    }
+
+    /// Returns an iterator over all pairs of adjacent elements, `(&self[i], &self[i + 1])`.
+    ///
+    /// The iterator is empty for arrays of fewer than two elements.
+    #[inline]
+    pub fn pairwise(
+        &self,
+    ) -> ::core::iter::Zip<slice::Iter<'_, T>, ::core::iter::Skip<slice::Iter<'_, T>>> {
+        self.as_slice().iter().zip(self.as_slice().iter().skip(1))
+    }
+
+    /// Checks if every element of the array satisfies the predicate,
+    /// stopping at the first one which does not.
+    ///
+    /// An empty array always returns `true`.
+    #[inline]
+    pub fn all<P>(&self, predicate: P) -> bool
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.as_slice().iter().all(predicate)
+    }
+
+    /// Checks if any element of the array satisfies the predicate,
+    /// stopping at the first one which does.
+    ///
+    /// An empty array always returns `false`.
+    #[inline]
+    pub fn any<P>(&self, predicate: P) -> bool
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.as_slice().iter().any(predicate)
+    }
+
+    /// Counts the elements of the array which satisfy the predicate.
+    #[inline]
+    pub fn count<P>(&self, mut predicate: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.as_slice().iter().filter(|x| predicate(x)).count()
+    }
+
+    /// Returns the element with the maximum key, or `None` if the array is empty.
+    ///
+    /// If several elements are equally maximum, the first one is returned.
+    #[inline]
+    pub fn max_by_key<K, F>(&self, mut f: F) -> Option<&T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        // `Iterator::max_by_key` returns the last maximum, which is the first one
+        // when iterating in reverse.
+        self.as_slice().iter().rev().max_by_key(|x| f(x))
+    }
+
+    /// Returns the element with the minimum key, or `None` if the array is empty.
+    ///
+    /// If several elements are equally minimum, the first one is returned.
+    #[inline]
+    pub fn min_by_key<K, F>(&self, mut f: F) -> Option<&T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.as_slice().iter().min_by_key(|x| f(x))
+    }
+
+    /// Searches for the last element satisfying the predicate, returning its index.
+    #[inline]
+    pub fn rposition<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.as_slice().iter().rposition(predicate)
+    }
+
+    /// Searches for the last element satisfying the predicate, returning a reference to it.
+    #[inline]
+    pub fn rfind<P>(&self, mut predicate: P) -> Option<&T>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.as_slice().iter().rev().find(|x| predicate(x))
+    }
+
+    /// Gathers elements into a new array, where element `j` of the result is a clone
+    /// of `self[indices[j]]`.
+    ///
+    /// Returns `None` if any of the indices is out of bounds.
+    pub fn gather<M>(&self, indices: &GenericArray<usize, M>) -> Option<GenericArray<T, M>>
+    where
+        T: Clone,
+        M: ArrayLength<usize> + ArrayLength<T>,
+    {
+        if indices.iter().any(|&i| i >= N::to_usize()) {
+            return None;
+        }
+
+        Some(GenericArray::generate(|j| unsafe {
+            self.get_unchecked(*indices.get_unchecked(j)).clone()
+        }))
+    }
+
+    /// Scatters `values` into the array, writing `values[j]` to `self[indices[j]]`
+    /// and dropping the overwritten elements.
+    ///
+    /// All indices are validated before anything is written, so on error
+    /// the array is left unchanged.
+    pub fn scatter<M>(
+        &mut self,
+        indices: &GenericArray<usize, M>,
+        values: GenericArray<T, M>,
+    ) -> Result<(), ScatterError>
+    where
+        M: ArrayLength<usize> + ArrayLength<T>,
+    {
+        for (j, &index) in indices.iter().enumerate() {
+            if index >= N::to_usize() {
+                return Err(ScatterError::OutOfBounds { index });
+            }
+
+            if indices[..j].contains(&index) {
+                return Err(ScatterError::Duplicate { index });
+            }
+        }
+
+        for (&index, value) in indices.iter().zip(values) {
+            unsafe {
+                *self.get_unchecked_mut(index) = value;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Permutes the array in place, so that element `i` afterwards is the element
+    /// previously at `self[perm[i]]`.
+    ///
+    /// The permutation is applied by following its cycles, using `perm` itself to
+    /// mark visited indices, and `perm` is restored before returning. It is validated
+    /// before anything is moved, so on error both the array and `perm` are left unchanged.
+    pub fn permute_in_place(
+        &mut self,
+        perm: &mut GenericArray<usize, N>,
+    ) -> Result<(), PermutationError>
+    where
+        N: ArrayLength<usize>,
+    {
+        // A `GenericArray<usize, N>` cannot have more than `isize::MAX` bytes,
+        // so the top bit of an in-bounds index is always clear.
+        const MARK: usize = !(usize::MAX >> 1);
+
+        let len = N::to_usize();
+
+        // Bounds are checked on the raw values before anything is marked, so
+        // that only in-bounds indices ever have the mark set and cleared.
+        if let Some(&index) = perm.iter().find(|&&index| index >= len) {
+            return Err(PermutationError::OutOfBounds { index });
+        }
+
+        for i in 0..len {
+            let index = perm[i] & !MARK;
+
+            if perm[index] & MARK != 0 {
+                for p in perm.iter_mut() {
+                    *p &= !MARK;
+                }
+
+                return Err(PermutationError::Duplicate { index });
+            }
+
+            perm[index] |= MARK;
+        }
+
+        // Every index is now marked exactly once; clearing the mark again
+        // records that its cycle has been visited.
+        for start in 0..len {
+            if perm[start] & MARK == 0 {
+                continue;
+            }
+
+            let mut current = start;
+            loop {
+                let next = perm[current] & !MARK;
+                perm[current] = next;
+
+                if next == start {
+                    break;
+                }
+
+                self.swap(current, next);
+                current = next;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sorts the array in place with an insertion sort when it has at most 16
+    /// elements, and with the slice `sort_unstable` otherwise.
+    ///
+    /// The choice is made per `N` when the method is monomorphized. Each element
+    /// is inserted by shifting the larger elements before it up by one. On the
+    /// insertion sort path the order of equal elements is preserved, which the
+    /// slice `sort_unstable` does not do and the slice `sort` needs `alloc` for.
+    ///
+    /// If a comparison panics, every element is still in the array, in some order.
+    pub fn insertion_sort_small(&mut self)
+    where
+        T: Ord,
+    {
+        /// Writes the element being inserted into the hole when dropped, so that
+        /// every element is in the array again even if a comparison panics.
+        struct Hole<T> {
+            value: ManuallyDrop<T>,
+            dest: *mut T,
+        }
+
+        impl<T> Drop for Hole<T> {
+            fn drop(&mut self) {
+                unsafe {
+                    ptr::copy_nonoverlapping(&*self.value, self.dest, 1);
+                }
+            }
+        }
+
+        if N::to_usize() <= 16 {
+            let base = self.as_mut_ptr();
+
+            for i in 1..N::to_usize() {
+                unsafe {
+                    if *base.add(i) >= *base.add(i - 1) {
+                        continue;
+                    }
+
+                    // Element `i` is moved out, leaving a hole which travels down
+                    // as the larger elements before it are shifted up.
+                    let mut hole = Hole {
+                        value: ManuallyDrop::new(ptr::read(base.add(i))),
+                        dest: base.add(i - 1),
+                    };
+                    ptr::copy_nonoverlapping(base.add(i - 1), base.add(i), 1);
+
+                    let mut j = i - 1;
+
+                    while j > 0 && *hole.value < *base.add(j - 1) {
+                        ptr::copy_nonoverlapping(base.add(j - 1), base.add(j), 1);
+                        j -= 1;
+                        hole.dest = base.add(j);
+                    }
+                }
+            }
+        } else {
+            self.as_mut_slice().sort_unstable();
+        }
+    }
+
+    /// Computes the rank of every element, the position it would have in the
+    /// sorted array, with ties broken by index.
+    pub fn ranks(&self) -> GenericArray<usize, N>
+    where
+        T: Ord,
+        N: ArrayLength<usize>,
+    {
+        let mut order = GenericArray::<usize, N>::generate(|i| i);
+
+        order
+            .as_mut_slice()
+            .sort_unstable_by(|&a, &b| self[a].cmp(&self[b]).then(a.cmp(&b)));
+
+        let mut ranks = GenericArray::<usize, N>::default();
+
+        for (rank, &i) in order.iter().enumerate() {
+            ranks[i] = rank;
+        }
+
+        ranks
+    }
+
+    /// Reorders the array for a radix-2 FFT, moving element `i` to the index whose
+    /// binary representation is that of `i` reversed.
+    ///
+    /// The length of the array must be a power of two, which is checked at compile time:
+    ///
+    /// ```compile_fail,E0277
+    /// # #[macro_use]
+    /// # extern crate generic_array;
+    /// # fn main() {
+    /// let a = arr![i32; 0, 1, 2, 3, 4, 5].bit_reverse_permute();
+    /// # }
+    /// ```
+    pub fn bit_reverse_permute(mut self) -> GenericArray<T, N>
+    where
+        N: PowerOfTwo,
+    {
+        let len = N::to_usize();
+
+        if len > 1 {
+            let shift = mem::size_of::<usize>() as u32 * 8 - len.trailing_zeros();
+
+            for i in 0..len {
+                let j = i.reverse_bits() >> shift;
+
+                if i < j {
+                    self.swap(i, j);
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Computes the maximum over a sliding window ending at each element, so that
+    /// element `i` of the result is the maximum of `self[i + 1 - window..=i]`,
+    /// truncated at the start of the array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    pub fn running_max(&self, window: usize) -> GenericArray<T, N>
+    where
+        T: Ord + Clone,
+    {
+        assert!(window > 0, "running_max window must not be zero");
+
+        GenericArray::generate(|i| {
+            let start = (i + 1).saturating_sub(window);
+
+            // The window always contains at least `self[i]`.
+            self[start..=i].iter().max().unwrap().clone()
+        })
+    }
+
+    /// Checks if the array reads the same forwards and backwards.
+    pub fn is_palindrome(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .zip(self.iter().rev())
+            .take(N::to_usize() / 2)
+            .all(|(a, b)| a == b)
+    }
+
+    /// Checks if the elements of the array are sorted in non-descending order.
+    #[inline]
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| a.partial_cmp(b))
+    }
+
+    /// Checks if the elements of the array are sorted using the given comparator function.
+    ///
+    /// The array is considered sorted if `compare` returns `Some(Less)` or `Some(Equal)`
+    /// for every pair of adjacent elements.
+    pub fn is_sorted_by<F>(&self, mut compare: F) -> bool
+    where
+        F: FnMut(&T, &T) -> Option<Ordering>,
+    {
+        self.windows(2).all(|w| {
+            matches!(
+                compare(&w[0], &w[1]),
+                Some(Ordering::Less) | Some(Ordering::Equal)
+            )
+        })
+    }
 }
 
 impl<T: Clone, N> GenericArray<T, N>
@@ -372,6 +1104,30 @@ where
     }
 }
 
+impl<T: Copy, N> GenericArray<T, N>
+where
+    N: ArrayLength<T>,
+{
+    /// Overwrites every element of the array with a copy of `value`.
+    #[inline]
+    pub fn fill(&mut self, value: T) {
+        for dst in self.iter_mut() {
+            *dst = value;
+        }
+    }
+
+    /// Copies the window `slice[start..start + N]` into a new array.
+    ///
+    /// Returns `None` if the window does not fit inside the slice.
+    #[inline]
+    pub fn copy_window(slice: &[T], start: usize) -> Option<GenericArray<T, N>> {
+        let end = start.checked_add(N::to_usize())?;
+        let window = slice.get(start..end)?;
+
+        Some(Self::clone_from_slice(window))
+    }
+}
+
 impl<T, N> GenericArray<T, N>
 where
     N: ArrayLength<T>,
@@ -389,22 +1145,106 @@ where
         if iter.len() == N::to_usize() {
             let mut destination = ArrayBuilder::new();
 
-            for (dst, src) in destination.array.iter_mut().zip(iter.into_iter()) {
+            for src in iter.take(N::to_usize()) {
                 unsafe {
-                    ptr::write(dst, src);
+                    destination.push(src);
                 }
+            }
+
+            Some(destination.into_inner())
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new `GenericArray` instance from the first `N` values of an iterator
+    /// of `Result`s.
+    ///
+    /// Stops at the first error, or returns `TryCollectError::TooShort` if the iterator
+    /// yields fewer than `N` values. In both cases the already collected values are dropped.
+    pub fn try_from_results<I, E>(iter: I) -> Result<Self, TryCollectError<E>>
+    where
+        I: IntoIterator<Item = Result<T, E>>,
+    {
+        let mut iter = iter.into_iter();
+        let mut destination = ArrayBuilder::new();
 
-                destination.position += 1;
+        for _ in 0..N::to_usize() {
+            match iter.next() {
+                Some(Ok(value)) => unsafe { destination.push(value) },
+                Some(Err(e)) => return Err(TryCollectError::Item(e)),
+                None => return Err(TryCollectError::TooShort(destination.position)),
             }
+        }
 
-            let array = unsafe { ptr::read(&destination.array) };
+        Ok(destination.into_inner())
+    }
 
-            mem::forget(destination);
+    /// Creates a new `GenericArray` instance from an iterator which must yield
+    /// exactly `N` values.
+    ///
+    /// If the iterator yields fewer values, or more than `N` values, the already
+    /// collected values are dropped, and the error tells which of the two happened.
+    /// At most one value past the first `N` is taken from the iterator.
+    pub fn from_iter_exact<I>(iter: I) -> Result<Self, ExactLenError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+        let mut destination = ArrayBuilder::new();
 
-            Some(ManuallyDrop::into_inner(array))
-        } else {
-            None
+        for _ in 0..N::to_usize() {
+            match iter.next() {
+                Some(value) => unsafe { destination.push(value) },
+                None => {
+                    return Err(ExactLenError::TooFew {
+                        got: destination.position,
+                        expected: N::to_usize(),
+                    })
+                }
+            }
+        }
+
+        if iter.next().is_some() {
+            return Err(ExactLenError::TooMany {
+                expected: N::to_usize(),
+            });
         }
+
+        Ok(destination.into_inner())
+    }
+}
+
+impl<T, N> GenericArray<T, N>
+where
+    N: ArrayLength<T> + ArrayLength<MaybeUninit<T>>,
+{
+    /// Creates an array of uninitialized elements, for example to be filled in
+    /// through a raw pointer by foreign code.
+    #[inline]
+    pub fn uninit() -> GenericArray<MaybeUninit<T>, N> {
+        GenericArray::generate(|_| MaybeUninit::uninit())
+    }
+
+    /// Converts an array of `MaybeUninit<T>` into an array of `T`.
+    ///
+    /// # Safety
+    ///
+    /// Every element of `array` must be fully initialized.
+    #[inline]
+    pub unsafe fn assume_init(array: GenericArray<MaybeUninit<T>, N>) -> GenericArray<T, N> {
+        transmute(array)
+    }
+
+    /// Views the array as an array of `MaybeUninit<T>`, allowing its elements
+    /// to be overwritten without dropping the previous values.
+    ///
+    /// # Safety
+    ///
+    /// Every element must still be initialized when the borrow ends.
+    #[inline]
+    pub unsafe fn as_uninit_mut(&mut self) -> &mut GenericArray<MaybeUninit<T>, N> {
+        &mut *(self as *mut Self as *mut GenericArray<MaybeUninit<T>, N>)
     }
 }
 
@@ -421,13 +1261,9 @@ where
 
         let defaults = ::core::iter::repeat(()).map(|_| T::default());
 
-        for (dst, src) in destination
-            .array
-            .iter_mut()
-            .zip(iter.into_iter().chain(defaults))
-        {
+        for src in iter.into_iter().chain(defaults).take(N::to_usize()) {
             unsafe {
-                ptr::write(dst, src);
+                destination.push(src);
             }
         }
 
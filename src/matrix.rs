@@ -0,0 +1,203 @@
+//! Nested `GenericArray`s are a convenient way to store small, fixed-size
+//! matrices. Since the storage of a `GenericArray` is contiguous, an
+//! `R`×`C` matrix has the exact same layout as a flat array of `R * C`
+//! elements, stored in row-major order. This module implements helpers
+//! for working with such matrices.
+
+use {transmute, ArrayLength, GenericArray};
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::{Add, Mul};
+use core::ptr;
+use typenum::operator_aliases::Prod;
+
+impl<T, C, R> GenericArray<GenericArray<T, C>, R>
+where
+    C: ArrayLength<T>,
+    R: ArrayLength<GenericArray<T, C>>,
+{
+    /// Views the matrix as a flat, row-major array reference.
+    ///
+    /// Element `[r][c]` of the matrix is element `[r * C + c]` of the flat array.
+    #[inline]
+    pub fn as_flat(&self) -> &GenericArray<T, Prod<R, C>>
+    where
+        R: Mul<C>,
+        Prod<R, C>: ArrayLength<T>,
+    {
+        unsafe { &*(self as *const Self as *const GenericArray<T, Prod<R, C>>) }
+    }
+
+    /// Views the matrix as a flat, row-major mutable array reference.
+    ///
+    /// Element `[r][c]` of the matrix is element `[r * C + c]` of the flat array.
+    #[inline]
+    pub fn as_flat_mut(&mut self) -> &mut GenericArray<T, Prod<R, C>>
+    where
+        R: Mul<C>,
+        Prod<R, C>: ArrayLength<T>,
+    {
+        unsafe { &mut *(self as *mut Self as *mut GenericArray<T, Prod<R, C>>) }
+    }
+
+    /// Reinterprets an `R`×`C` matrix as an `R2`×`C2` matrix with the same number of
+    /// elements, keeping them in the same row-major order.
+    ///
+    /// The number of elements must match at the type level, i.e. `R2 * C2 == R * C`.
+    pub fn reshape<R2, C2>(self) -> GenericArray<GenericArray<T, C2>, R2>
+    where
+        R: Mul<C>,
+        R2: Mul<C2, Output = Prod<R, C>> + ArrayLength<GenericArray<T, C2>>,
+        C2: ArrayLength<T>,
+    {
+        // Both matrices have the layout of a flat array of `R * C` elements.
+        unsafe { transmute(self) }
+    }
+
+    /// Reinterprets an `R`×`C` matrix as an `R2`×`C2` matrix, checking at runtime
+    /// that both have the same number of elements.
+    ///
+    /// Returns the matrix unchanged if they do not.
+    pub fn try_reshape<R2, C2>(self) -> Result<GenericArray<GenericArray<T, C2>, R2>, Self>
+    where
+        R2: ArrayLength<GenericArray<T, C2>>,
+        C2: ArrayLength<T>,
+    {
+        if R::to_usize() * C::to_usize() == R2::to_usize() * C2::to_usize() {
+            Ok(unsafe { transmute(self) })
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Transposes an `R`×`C` matrix into a `C`×`R` matrix, moving every element.
+    ///
+    /// Element `[c][r]` of the output is element `[r][c]` of the input.
+    pub fn transpose(self) -> GenericArray<GenericArray<T, R>, C>
+    where
+        R: ArrayLength<T>,
+        C: ArrayLength<GenericArray<T, R>>,
+    {
+        let (rows, cols) = (R::to_usize(), C::to_usize());
+
+        let source = ManuallyDrop::new(self);
+        let mut output = MaybeUninit::<GenericArray<GenericArray<T, R>, C>>::uninit();
+
+        let source_ptr = &*source as *const Self as *const T;
+        let output_ptr = output.as_mut_ptr() as *mut T;
+
+        // Every source element is read exactly once and every output element is
+        // written exactly once. Nothing in between can panic, so once the loop is
+        // done ownership of all elements has been moved over to `output`.
+        unsafe {
+            for r in 0..rows {
+                for c in 0..cols {
+                    ptr::write(
+                        output_ptr.add(c * rows + r),
+                        ptr::read(source_ptr.add(r * cols + c)),
+                    );
+                }
+            }
+
+            output.assume_init()
+        }
+    }
+
+    /// Maps a function over the elements of an `R`×`C` matrix by reference,
+    /// producing the transposed `C`×`R` matrix of the results.
+    ///
+    /// Element `[c][r]` of the output is `f(&self[r][c])`.
+    pub fn transpose_map<U, F>(&self, mut f: F) -> GenericArray<GenericArray<U, R>, C>
+    where
+        F: FnMut(&T) -> U,
+        R: ArrayLength<U>,
+        C: ArrayLength<GenericArray<U, R>>,
+    {
+        GenericArray::generate(|c| GenericArray::generate(|r| f(&self[r][c])))
+    }
+
+    /// Maps a function over the elements of a matrix by reference, also passing
+    /// the row and column of each element.
+    ///
+    /// If the mapping function panics, any already initialized elements will be dropped.
+    pub fn map2d<U, F>(&self, mut f: F) -> GenericArray<GenericArray<U, C>, R>
+    where
+        F: FnMut(usize, usize, &T) -> U,
+        C: ArrayLength<U>,
+        R: ArrayLength<GenericArray<U, C>>,
+    {
+        GenericArray::generate(|r| GenericArray::generate(|c| f(r, c, &self[r][c])))
+    }
+
+    /// Folds every element of the matrix into an accumulator, visiting them
+    /// in row-major order along with their row and column.
+    ///
+    /// If `f` panics, any elements not yet visited will be dropped.
+    pub fn fold2d<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, usize, usize, T) -> B,
+    {
+        let mut acc = init;
+
+        for (r, row) in self.into_iter().enumerate() {
+            for (c, x) in row.into_iter().enumerate() {
+                acc = f(acc, r, c, x);
+            }
+        }
+
+        acc
+    }
+
+    /// Multiplies an `R`×`C` matrix by a vector of length `C`.
+    ///
+    /// Each element of the resulting vector starts from `T::default()`,
+    /// so a matrix with no columns produces a vector of defaults.
+    pub fn mul_vec(&self, v: &GenericArray<T, C>) -> GenericArray<T, R>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Default + Clone,
+        R: ArrayLength<T>,
+    {
+        self.map_ref(|row| {
+            row.iter()
+                .zip(v.iter())
+                .fold(T::default(), |acc, (a, b)| acc + a.clone() * b.clone())
+        })
+    }
+}
+
+impl<T, N> GenericArray<GenericArray<T, N>, N>
+where
+    N: ArrayLength<T> + ArrayLength<GenericArray<T, N>>,
+{
+    /// Creates the `N`×`N` identity matrix, with `T::from(1)` on the diagonal
+    /// and `T::from(0)` everywhere else.
+    pub fn identity() -> Self
+    where
+        T: From<u8>,
+    {
+        GenericArray::generate(|r| GenericArray::generate(|c| T::from((r == c) as u8)))
+    }
+
+    /// Checks if a square matrix is equal to its transpose.
+    pub fn is_symmetric(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        (0..N::to_usize()).all(|r| (0..r).all(|c| self[r][c] == self[c][r]))
+    }
+}
+
+impl<T, R> GenericArray<T, R>
+where
+    R: ArrayLength<T>,
+{
+    /// Computes the outer product of two vectors, the `R`×`C` matrix whose element
+    /// `[r][c]` is `self[r] * other[c]`.
+    pub fn outer<C>(&self, other: &GenericArray<T, C>) -> GenericArray<GenericArray<T, C>, R>
+    where
+        T: Mul<Output = T> + Clone,
+        C: ArrayLength<T>,
+        R: ArrayLength<GenericArray<T, C>>,
+    {
+        self.map_ref(|a| other.map_ref(|b| a.clone() * b.clone()))
+    }
+}
@@ -0,0 +1,290 @@
+//! Arithmetic helpers for `GenericArray`s of numbers.
+
+use {ArrayBuilder, ArrayLength, GenericArray, OverflowError};
+use core::convert::TryFrom;
+use core::ops::{Add, Mul, Sub};
+use typenum::bit::B1;
+use typenum::operator_aliases::Sub1;
+
+impl<T, N> GenericArray<T, N>
+where
+    N: ArrayLength<T>,
+{
+    /// Sums all elements of the array, consuming it.
+    ///
+    /// An empty array sums to `T::default()`.
+    #[inline]
+    pub fn sum(self) -> T
+    where
+        T: Add<Output = T> + Default,
+    {
+        self.into_iter().fold(T::default(), |acc, x| acc + x)
+    }
+
+    /// Sums all elements of the array after converting them into a wider type `W`,
+    /// avoiding overflow of narrow element types.
+    ///
+    /// An empty array sums to `W::default()`.
+    #[inline]
+    pub fn sum_as<W>(&self) -> W
+    where
+        T: Clone,
+        W: From<T> + Add<Output = W> + Default,
+    {
+        self.iter()
+            .fold(W::default(), |acc, x| acc + W::from(x.clone()))
+    }
+
+    /// Computes the running totals of the array, `[a0, a0 + a1, a0 + a1 + a2, ...]`,
+    /// consuming it.
+    pub fn cumsum(self) -> GenericArray<T, N>
+    where
+        T: Add<Output = T> + Clone,
+    {
+        let mut destination = ArrayBuilder::new();
+        let mut total: Option<T> = None;
+
+        for x in self {
+            let sum = match total.take() {
+                Some(total) => total + x,
+                None => x,
+            };
+
+            unsafe {
+                destination.push(sum.clone());
+            }
+
+            total = Some(sum);
+        }
+
+        destination.into_inner()
+    }
+
+    /// Clamps every element of the array into the range `[lo, hi]`, consuming it.
+    ///
+    /// `lo` must not be greater than `hi`, which is checked in debug builds.
+    pub fn clamp_each(self, lo: T, hi: T) -> GenericArray<T, N>
+    where
+        T: Ord + Clone,
+    {
+        debug_assert!(lo <= hi, "clamp_each: lo is greater than hi");
+
+        self.map(|x| {
+            if x < lo {
+                lo.clone()
+            } else if x > hi {
+                hi.clone()
+            } else {
+                x
+            }
+        })
+    }
+
+    /// Converts every element into another numeric type, clamping values outside
+    /// its range to the nearest representable one instead of wrapping.
+    #[inline]
+    pub fn saturating_cast<U>(self) -> GenericArray<U, N>
+    where
+        U: SaturatingFrom<T>,
+        N: ArrayLength<U>,
+    {
+        self.map(U::saturating_from)
+    }
+
+    /// Multiplies all elements of the array, consuming it.
+    ///
+    /// The product of an empty array is `T::from(1)`.
+    #[inline]
+    pub fn product(self) -> T
+    where
+        T: Mul<Output = T> + From<u8>,
+    {
+        self.into_iter().fold(T::from(1), |acc, x| acc * x)
+    }
+}
+
+impl<T, N> GenericArray<T, N>
+where
+    N: ArrayLength<T> + Sub<B1>,
+    Sub1<N>: ArrayLength<T>,
+{
+    /// Computes the differences between successive elements, `self[i + 1] - self[i]`.
+    ///
+    /// The array must not be empty, which is checked at compile time:
+    ///
+    /// ```compile_fail,E0599
+    /// # #[macro_use]
+    /// # extern crate generic_array;
+    /// # fn main() {
+    /// let d = arr![i32;].diff();
+    /// # }
+    /// ```
+    pub fn diff(&self) -> GenericArray<T, Sub1<N>>
+    where
+        T: Sub<Output = T> + Clone,
+    {
+        GenericArray::generate(|i| self[i + 1].clone() - self[i].clone())
+    }
+}
+
+/// Conversion from another integer type, clamping values outside the range of
+/// `Self` to `Self::MIN` or `Self::MAX`.
+pub trait SaturatingFrom<T>: Sized {
+    /// Converts `value`, saturating at the bounds of `Self`.
+    fn saturating_from(value: T) -> Self;
+}
+
+macro_rules! impl_saturating_from {
+    ($($from: ty),*) => {
+        $(
+            impl_saturating_from!(@into $from => u8, u16, u32, u64, u128, usize,
+                                                 i8, i16, i32, i64, i128, isize);
+        )*
+    };
+    (@into $from: ty => $($to: ty),*) => {
+        $(
+            impl SaturatingFrom<$from> for $to {
+                #[inline]
+                fn saturating_from(value: $from) -> $to {
+                    // The conversion can only fail by being too far from zero.
+                    <$to>::try_from(value).unwrap_or(if value > 0 { <$to>::MAX } else { <$to>::MIN })
+                }
+            }
+        )*
+    };
+}
+
+impl_saturating_from!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_integer {
+    ($($ty: ty),*) => {
+        $(
+            impl<N> GenericArray<$ty, N>
+            where
+                N: ArrayLength<$ty>,
+            {
+                /// Sums all elements of the array, returning `None` if the sum overflows.
+                #[inline]
+                pub fn checked_sum(self) -> Option<$ty> {
+                    self.iter().try_fold(0, |acc: $ty, &x| acc.checked_add(x))
+                }
+
+                /// Adds `rhs` to the array element-wise, in place.
+                ///
+                /// If any of the additions would overflow, an error is returned
+                /// and the array is left unchanged.
+                pub fn add_assign_checked(&mut self, rhs: &Self) -> Result<(), OverflowError> {
+                    if let Some(index) = self
+                        .iter()
+                        .zip(rhs.iter())
+                        .position(|(a, &b)| a.checked_add(b).is_none())
+                    {
+                        return Err(OverflowError::new(index));
+                    }
+
+                    for (a, &b) in self.iter_mut().zip(rhs.iter()) {
+                        *a += b;
+                    }
+
+                    Ok(())
+                }
+
+                /// Element-wise saturating addition.
+                #[inline]
+                pub fn saturating_add(self, rhs: Self) -> Self {
+                    self.zip(rhs, <$ty>::saturating_add)
+                }
+
+                /// Element-wise saturating subtraction.
+                #[inline]
+                pub fn saturating_sub(self, rhs: Self) -> Self {
+                    self.zip(rhs, <$ty>::saturating_sub)
+                }
+
+                /// Element-wise saturating multiplication.
+                #[inline]
+                pub fn saturating_mul(self, rhs: Self) -> Self {
+                    self.zip(rhs, <$ty>::saturating_mul)
+                }
+
+                /// Element-wise wrapping addition.
+                #[inline]
+                pub fn wrapping_add(self, rhs: Self) -> Self {
+                    self.zip(rhs, <$ty>::wrapping_add)
+                }
+
+                /// Element-wise wrapping subtraction.
+                #[inline]
+                pub fn wrapping_sub(self, rhs: Self) -> Self {
+                    self.zip(rhs, <$ty>::wrapping_sub)
+                }
+
+                /// Element-wise wrapping multiplication.
+                #[inline]
+                pub fn wrapping_mul(self, rhs: Self) -> Self {
+                    self.zip(rhs, <$ty>::wrapping_mul)
+                }
+
+                /// Reverses the byte order of every element.
+                #[inline]
+                pub fn swap_bytes_each(self) -> Self {
+                    self.map(<$ty>::swap_bytes)
+                }
+
+                /// Converts every element to big endian from the target's endianness.
+                #[inline]
+                pub fn to_be(self) -> Self {
+                    self.map(<$ty>::to_be)
+                }
+
+                /// Converts every element to little endian from the target's endianness.
+                #[inline]
+                pub fn to_le(self) -> Self {
+                    self.map(<$ty>::to_le)
+                }
+
+                /// Converts every element from big endian to the target's endianness.
+                #[inline]
+                pub fn from_be(self) -> Self {
+                    self.map(<$ty>::from_be)
+                }
+
+                /// Converts every element from little endian to the target's endianness.
+                #[inline]
+                pub fn from_le(self) -> Self {
+                    self.map(<$ty>::from_le)
+                }
+            }
+        )*
+    }
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(feature = "std")]
+macro_rules! impl_float {
+    ($($ty: ty),*) => {
+        $(
+            impl<N> GenericArray<$ty, N>
+            where
+                N: ArrayLength<$ty>,
+            {
+                /// Divides the vector by its Euclidean norm, producing a unit vector.
+                ///
+                /// Returns `None` if the norm is zero, including for an empty array.
+                pub fn normalized(self) -> Option<GenericArray<$ty, N>> {
+                    let norm = self.iter().map(|x| x * x).sum::<$ty>().sqrt();
+
+                    if norm == 0.0 {
+                        None
+                    } else {
+                        Some(self.map(|x| x / norm))
+                    }
+                }
+            }
+        )*
+    }
+}
+
+#[cfg(feature = "std")]
+impl_float!(f32, f64);
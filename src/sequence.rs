@@ -1,9 +1,41 @@
 //! Useful traits for manipulating sequences of data stored in `GenericArray`s
 
 use super::*;
-use core::{mem, ptr};
-use core::ops::{Add, Sub};
+use core::{mem, ptr, slice};
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::{Add, Div, Mul, Sub};
+use typenum::consts::U2;
 use typenum::operator_aliases::*;
+use typenum::type_operators::{IsLess, IsLessOrEqual, PartialDiv};
+use typenum::True;
+
+/// The disjoint parts returned by `GenericArray::split_mut`.
+type SplitMut<'a, T, N, K> = (
+    &'a mut GenericArray<T, K>,
+    &'a mut GenericArray<T, Diff<N, K>>,
+);
+
+/// The array of chunks returned by `GenericArray::into_chunks_checked`.
+type Chunks<T, N, C> = GenericArray<GenericArray<T, C>, Quot<N, C>>;
+
+/// The even- and odd-indexed halves returned by `GenericArray::split_parity`.
+type SplitParity<T, N> = (
+    GenericArray<T, Quot<Add1<N>, U2>>,
+    GenericArray<T, Quot<N, U2>>,
+);
+
+/// The three parts returned by `GenericArray::split3`.
+type Split3<T, N, A, B> = (
+    GenericArray<T, A>,
+    GenericArray<T, B>,
+    GenericArray<T, Diff<Diff<N, A>, B>>,
+);
+
+/// The result of `GenericArray::insert`, giving back the array and value on failure.
+type InsertResult<T, N> = Result<GenericArray<T, Add1<N>>, (GenericArray<T, N>, T)>;
+
+/// The result of `GenericArray::remove`, giving back the array on failure.
+type RemoveResult<T, N> = Result<(GenericArray<T, Sub1<N>>, T), GenericArray<T, N>>;
 
 /// Defines some `GenericArray` sequence with an associated length.
 ///
@@ -98,27 +130,29 @@ where
     type Longer = GenericArray<T, Add1<N>>;
 
     fn append(self, last: T) -> Self::Longer {
-        let mut longer: Self::Longer = unsafe { mem::uninitialized() };
+        let mut longer = MaybeUninit::<Self::Longer>::uninit();
+
+        let longer_ptr = longer.as_mut_ptr() as *mut T;
 
         unsafe {
-            ptr::write(longer.as_mut_ptr() as *mut _, self);
-            ptr::write(&mut longer[N::to_usize()], last);
-        }
+            ptr::write(longer_ptr as *mut _, self);
+            ptr::write(longer_ptr.add(N::to_usize()), last);
 
-        longer
+            longer.assume_init()
+        }
     }
 
     fn prepend(self, first: T) -> Self::Longer {
-        let mut longer: Self::Longer = unsafe { mem::uninitialized() };
+        let mut longer = MaybeUninit::<Self::Longer>::uninit();
 
-        let longer_ptr = longer.as_mut_ptr();
+        let longer_ptr = longer.as_mut_ptr() as *mut T;
 
         unsafe {
-            ptr::write(longer_ptr as *mut _, first);
-            ptr::write(longer_ptr.offset(1) as *mut _, self);
-        }
+            ptr::write(longer_ptr, first);
+            ptr::write(longer_ptr.add(1) as *mut _, self);
 
-        longer
+            longer.assume_init()
+        }
     }
 }
 
@@ -193,6 +227,35 @@ where
     }
 }
 
+/// Macro sorting a `GenericArray` in place with a fixed sorting network, given as a
+/// list of compare-exchange pairs of `typenum` index names.
+///
+/// Each pair `(I, J)` swaps the elements at `I` and `J` if they are out of order, using
+/// `GenericArray::swap_const`, so every index is checked against the length at compile time.
+/// Example:
+///
+/// ```ignore
+/// let mut a = arr![i32; 3, 1, 4, 2];
+///
+/// sorting_network!(a; (U0, U1), (U2, U3), (U0, U2), (U1, U3), (U1, U2));
+///
+/// assert_eq!(a, arr![i32; 1, 2, 3, 4]);
+/// ```
+#[macro_export]
+macro_rules! sorting_network {
+    ($array:expr; $(($i:ident, $j:ident)),*) => ({
+        let array = &mut $array;
+
+        $(
+            if array[<$crate::typenum::$j as $crate::typenum::Unsigned>::USIZE]
+                < array[<$crate::typenum::$i as $crate::typenum::Unsigned>::USIZE]
+            {
+                array.swap_const::<$crate::typenum::$i, $crate::typenum::$j>();
+            }
+        )*
+    });
+}
+
 /// Defines `GenericSequence`s which can be joined together, forming a larger array.
 pub unsafe trait Concat<T, M>: GenericSequence<T>
 where
@@ -218,15 +281,546 @@ where
     type Output = GenericArray<T, Sum<N, M>>;
 
     fn concat(self, rest: Self::Rest) -> Self::Output {
-        let mut output: Self::Output = unsafe { mem::uninitialized() };
+        let mut output = MaybeUninit::<Self::Output>::uninit();
 
-        let output_ptr = output.as_mut_ptr();
+        let output_ptr = output.as_mut_ptr() as *mut T;
 
         unsafe {
             ptr::write(output_ptr as *mut _, self);
-            ptr::write(output_ptr.offset(N::to_usize() as isize) as *mut _, rest);
+            ptr::write(output_ptr.add(N::to_usize()) as *mut _, rest);
+
+            output.assume_init()
+        }
+    }
+}
+
+impl<T, N> GenericArray<T, N>
+where
+    N: ArrayLength<T>,
+{
+    /// Concatenates the array with `rest`, writing the result into the
+    /// uninitialized array `out` instead of returning it.
+    ///
+    /// Afterwards every element of `out` is initialized, and may be taken out
+    /// with `GenericArray::assume_init`. Any values previously held by `out`
+    /// are overwritten without being dropped.
+    ///
+    /// Example:
+    ///
+    /// ```ignore
+    /// let mut out = GenericArray::<i32, U5>::uninit();
+    ///
+    /// arr![i32; 1, 2, 3].concat_into(arr![i32; 4, 5], &mut out);
+    ///
+    /// assert_eq!(unsafe { GenericArray::assume_init(out) }, arr![i32; 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn concat_into<M>(
+        self,
+        rest: GenericArray<T, M>,
+        out: &mut GenericArray<MaybeUninit<T>, Sum<N, M>>,
+    ) where
+        N: Add<M>,
+        M: ArrayLength<T>,
+        Sum<N, M>: ArrayLength<MaybeUninit<T>>,
+    {
+        let out_ptr = out.as_mut_ptr() as *mut T;
+
+        unsafe {
+            ptr::write(out_ptr as *mut GenericArray<T, N>, self);
+            ptr::write(out_ptr.add(N::to_usize()) as *mut GenericArray<T, M>, rest);
+        }
+    }
+
+    /// Merges two individually sorted arrays into one sorted array, moving their elements.
+    ///
+    /// The merge is stable: on ties, elements of `self` come before those of `other`.
+    /// If the arrays are not sorted, the elements are still all moved over,
+    /// but their order is unspecified.
+    pub fn merge<M>(self, other: GenericArray<T, M>) -> GenericArray<T, Sum<N, M>>
+    where
+        T: Ord,
+        N: Add<M>,
+        M: ArrayLength<T>,
+        Sum<N, M>: ArrayLength<T>,
+    {
+        let mut left = self.into_iter().peekable();
+        let mut right = other.into_iter().peekable();
+        let mut destination = ArrayBuilder::new();
+
+        loop {
+            let take_right = match (left.peek(), right.peek()) {
+                (Some(a), Some(b)) => b < a,
+                (Some(_), None) => false,
+                (None, _) => true,
+            };
+
+            let next = if take_right {
+                right.next()
+            } else {
+                left.next()
+            };
+
+            match next {
+                Some(value) => unsafe { destination.push(value) },
+                None => break,
+            }
+        }
+
+        destination.into_inner()
+    }
+
+    /// Inserts `value` at position `index`, shifting all elements after it to the right,
+    /// and returns the longer array.
+    ///
+    /// Returns the array and the value unchanged if `index` is greater than `N`.
+    ///
+    /// Example:
+    ///
+    /// ```ignore
+    /// let a = arr![i32; 1, 2, 4].insert(2, 3);
+    ///
+    /// assert_eq!(a.ok(), Some(arr![i32; 1, 2, 3, 4]));
+    /// ```
+    pub fn insert(self, index: usize, value: T) -> InsertResult<T, N>
+    where
+        N: Add<B1>,
+        Add1<N>: ArrayLength<T>,
+    {
+        if index > N::to_usize() {
+            return Err((self, value));
+        }
+
+        let source = ManuallyDrop::new(self);
+        let mut longer = MaybeUninit::<GenericArray<T, Add1<N>>>::uninit();
+
+        let source_ptr = source.as_ptr();
+        let longer_ptr = longer.as_mut_ptr() as *mut T;
+
+        // Every element is moved exactly once, and nothing here can panic.
+        unsafe {
+            ptr::copy_nonoverlapping(source_ptr, longer_ptr, index);
+            ptr::write(longer_ptr.add(index), value);
+            ptr::copy_nonoverlapping(
+                source_ptr.add(index),
+                longer_ptr.add(index + 1),
+                N::to_usize() - index,
+            );
+
+            Ok(longer.assume_init())
+        }
+    }
+
+    /// Removes the element at position `index`, shifting all elements after it to the left,
+    /// and returns the shorter array along with the removed element.
+    ///
+    /// Returns the array unchanged if `index` is out of bounds.
+    ///
+    /// Example:
+    ///
+    /// ```ignore
+    /// let (a, removed) = arr![i32; 1, 2, 3, 4].remove(1).ok().unwrap();
+    ///
+    /// assert_eq!(a, arr![i32; 1, 3, 4]);
+    /// assert_eq!(removed, 2);
+    /// ```
+    pub fn remove(self, index: usize) -> RemoveResult<T, N>
+    where
+        N: Sub<B1>,
+        Sub1<N>: ArrayLength<T>,
+    {
+        if index >= N::to_usize() {
+            return Err(self);
+        }
+
+        let source = ManuallyDrop::new(self);
+        let mut shorter = MaybeUninit::<GenericArray<T, Sub1<N>>>::uninit();
+
+        let source_ptr = source.as_ptr();
+        let shorter_ptr = shorter.as_mut_ptr() as *mut T;
+
+        // Every element is moved exactly once, and nothing here can panic.
+        unsafe {
+            let removed = ptr::read(source_ptr.add(index));
+
+            ptr::copy_nonoverlapping(source_ptr, shorter_ptr, index);
+            ptr::copy_nonoverlapping(
+                source_ptr.add(index + 1),
+                shorter_ptr.add(index),
+                N::to_usize() - index - 1,
+            );
+
+            Ok((shorter.assume_init(), removed))
+        }
+    }
+
+    /// Splits an array so that the *second* part has length `K`,
+    /// returning the separate parts of the array.
+    ///
+    /// This is the mirror image of `Split`, which names the length of the first part.
+    ///
+    /// Example:
+    ///
+    /// ```ignore
+    /// let a = arr![i32; 1, 2, 3, 4, 5];
+    ///
+    /// let (init, tail) = a.rsplit::<U3>();
+    ///
+    /// assert_eq!(init, arr![i32; 1, 2]);
+    /// assert_eq!(tail, arr![i32; 3, 4, 5]);
+    /// ```
+    pub fn rsplit<K>(self) -> (GenericArray<T, Diff<N, K>>, GenericArray<T, K>)
+    where
+        K: ArrayLength<T>,
+        N: Sub<K>,
+        Diff<N, K>: ArrayLength<T>,
+    {
+        let init_ptr = self.as_ptr();
+        let tail_ptr = unsafe { init_ptr.add(Diff::<N, K>::to_usize()) };
+
+        let init = unsafe { ptr::read(init_ptr as _) };
+        let tail = unsafe { ptr::read(tail_ptr as _) };
+
+        mem::forget(self);
+
+        (init, tail)
+    }
+
+    /// Removes the last `K` elements of an array, returning the kept
+    /// first `N - K` elements and the removed ones.
+    ///
+    /// This is equivalent to `rsplit`, named after `Vec::split_off`.
+    #[inline]
+    pub fn split_off<K>(self) -> (GenericArray<T, Diff<N, K>>, GenericArray<T, K>)
+    where
+        K: ArrayLength<T>,
+        N: Sub<K>,
+        Diff<N, K>: ArrayLength<T>,
+    {
+        self.rsplit()
+    }
+
+    /// Splits an array into three parts, of lengths `A`, `B` and `N - A - B`,
+    /// returning the separate parts of the array.
+    ///
+    /// Example:
+    ///
+    /// ```ignore
+    /// let a = arr![i32; 1, 2, 3, 4, 5, 6];
+    ///
+    /// let (head, body, tail) = a.split3::<U1, U3>();
+    ///
+    /// assert_eq!(head, arr![i32; 1]);
+    /// assert_eq!(body, arr![i32; 2, 3, 4]);
+    /// assert_eq!(tail, arr![i32; 5, 6]);
+    /// ```
+    pub fn split3<A, B>(self) -> Split3<T, N, A, B>
+    where
+        A: ArrayLength<T>,
+        B: ArrayLength<T>,
+        N: Sub<A>,
+        Diff<N, A>: Sub<B>,
+        Diff<Diff<N, A>, B>: ArrayLength<T>,
+    {
+        let head_ptr = self.as_ptr();
+        let body_ptr = unsafe { head_ptr.add(A::to_usize()) };
+        let tail_ptr = unsafe { body_ptr.add(B::to_usize()) };
+
+        let head = unsafe { ptr::read(head_ptr as _) };
+        let body = unsafe { ptr::read(body_ptr as _) };
+        let tail = unsafe { ptr::read(tail_ptr as _) };
+
+        mem::forget(self);
+
+        (head, body, tail)
+    }
+
+    /// Rotates the array left by the compile-time amount `K`, which must be less than `N`.
+    ///
+    /// This is equivalent to `rotate_left(K::to_usize())`, but the rotation amount
+    /// is a constant the compiler can fully unroll for.
+    #[inline]
+    pub fn rotate_left_const<K>(mut self) -> GenericArray<T, N>
+    where
+        K: Unsigned + IsLess<N, Output = True>,
+    {
+        self.rotate_left(K::to_usize());
+        self
+    }
+
+    /// Swaps the elements at the compile-time indices `I` and `J`, which must both be
+    /// less than `N`:
+    ///
+    /// ```compile_fail,E0271
+    /// # #[macro_use]
+    /// # extern crate generic_array;
+    /// # use generic_array::typenum::{U0, U3};
+    /// # fn main() {
+    /// let mut a = arr![i32; 1, 2, 3];
+    ///
+    /// a.swap_const::<U0, U3>();
+    /// # }
+    /// ```
+    #[inline]
+    pub fn swap_const<I, J>(&mut self)
+    where
+        I: Unsigned + IsLess<N, Output = True>,
+        J: Unsigned + IsLess<N, Output = True>,
+    {
+        self.swap(I::to_usize(), J::to_usize());
+    }
+
+    /// Returns a typed reference to the `Len` elements starting at index `Start`.
+    ///
+    /// The sub-array must lie within the array, which is checked at compile time.
+    #[inline]
+    pub fn sub<Start, Len>(&self) -> &GenericArray<T, Len>
+    where
+        Start: Unsigned + Add<Len>,
+        Len: ArrayLength<T>,
+        Sum<Start, Len>: IsLessOrEqual<N, Output = True>,
+    {
+        unsafe { &*(self.as_ptr().add(Start::to_usize()) as *const GenericArray<T, Len>) }
+    }
+
+    /// Returns a typed mutable reference to the `Len` elements starting at index `Start`.
+    ///
+    /// The sub-array must lie within the array, which is checked at compile time.
+    #[inline]
+    pub fn sub_mut<Start, Len>(&mut self) -> &mut GenericArray<T, Len>
+    where
+        Start: Unsigned + Add<Len>,
+        Len: ArrayLength<T>,
+        Sum<Start, Len>: IsLessOrEqual<N, Output = True>,
+    {
+        unsafe { &mut *(self.as_mut_ptr().add(Start::to_usize()) as *mut GenericArray<T, Len>) }
+    }
+
+    /// Splits an array reference into a typed reference to the first `K` elements
+    /// and a slice of the remaining `N - K` elements.
+    #[inline]
+    pub fn split_array_ref<K>(&self) -> (&GenericArray<T, K>, &[T])
+    where
+        K: ArrayLength<T>,
+        N: Sub<K>,
+    {
+        let (head, tail) = self.split_at(K::to_usize());
+
+        (GenericArray::from_slice(head), tail)
+    }
+
+    /// Splits an array reference into a typed reference to the first `C` elements
+    /// and a slice of the rest, checking the length at runtime.
+    ///
+    /// Unlike `split_array_ref`, this does not require `C <= N` at compile time,
+    /// and returns `None` if `C` is greater than `N`.
+    #[inline]
+    pub fn split_first_chunk<C>(&self) -> Option<(&GenericArray<T, C>, &[T])>
+    where
+        C: ArrayLength<T>,
+    {
+        if C::to_usize() > N::to_usize() {
+            return None;
         }
 
-        output
+        let (head, tail) = self.split_at(C::to_usize());
+
+        Some((GenericArray::from_slice(head), tail))
+    }
+
+    /// Applies `f` to every window of `W` consecutive elements by reference,
+    /// collecting the `N - W + 1` results into an array.
+    pub fn windows_fold<W, U, F>(&self, mut f: F) -> GenericArray<U, Add1<Diff<N, W>>>
+    where
+        W: ArrayLength<T>,
+        N: Sub<W>,
+        Diff<N, W>: Add<B1>,
+        Add1<Diff<N, W>>: ArrayLength<U>,
+        F: FnMut(&GenericArray<T, W>) -> U,
+    {
+        GenericArray::generate(|i| f(GenericArray::from_slice(&self[i..i + W::to_usize()])))
+    }
+
+    /// Clones every window of `W` consecutive elements, collecting the `N - W + 1`
+    /// windows into the rows of a matrix.
+    ///
+    /// If cloning an element panics, any already cloned elements will be dropped.
+    pub fn windows_collect<W>(&self) -> GenericArray<GenericArray<T, W>, Add1<Diff<N, W>>>
+    where
+        T: Clone,
+        W: ArrayLength<T>,
+        N: Sub<W>,
+        Diff<N, W>: Add<B1>,
+        Add1<Diff<N, W>>: ArrayLength<GenericArray<T, W>>,
+    {
+        self.windows_fold(|window| window.clone())
+    }
+
+    /// Repeats the array `M` times, cloning its elements into a new array
+    /// of length `N * M`.
+    ///
+    /// If cloning an element panics, any already cloned elements will be dropped.
+    pub fn tile<M>(&self) -> GenericArray<T, Prod<N, M>>
+    where
+        T: Clone,
+        N: Mul<M>,
+        Prod<N, M>: ArrayLength<T>,
+    {
+        GenericArray::generate(|i| self[i % N::to_usize()].clone())
+    }
+
+    /// Splits a mutable array reference into disjoint mutable references to
+    /// the first `K` elements and the remaining `N - K` elements.
+    #[inline]
+    pub fn split_mut<K>(&mut self) -> SplitMut<'_, T, N, K>
+    where
+        K: ArrayLength<T>,
+        N: Sub<K>,
+        Diff<N, K>: ArrayLength<T>,
+    {
+        let (head, tail) = self.split_at_mut(K::to_usize());
+
+        (
+            GenericArray::from_mut_slice(head),
+            GenericArray::from_mut_slice(tail),
+        )
+    }
+
+    /// Views the array as a slice of `N / C` consecutive sub-arrays of length `C`.
+    ///
+    /// The length of the array must be a multiple of `C`.
+    #[inline]
+    pub fn as_chunk_slice<C>(&self) -> &[GenericArray<T, C>]
+    where
+        C: ArrayLength<T>,
+        N: PartialDiv<C>,
+    {
+        unsafe {
+            slice::from_raw_parts(
+                self.as_ptr() as *const GenericArray<T, C>,
+                N::to_usize() / C::to_usize(),
+            )
+        }
+    }
+
+    /// Applies `f` to each of the `N / C` consecutive chunks of length `C` by reference,
+    /// collecting the results into an array.
+    ///
+    /// The length of the array must be a multiple of `C`.
+    pub fn chunk_fold<C, U, F>(&self, mut f: F) -> GenericArray<U, PartialQuot<N, C>>
+    where
+        C: ArrayLength<T>,
+        N: PartialDiv<C>,
+        PartialQuot<N, C>: ArrayLength<U>,
+        F: FnMut(&GenericArray<T, C>) -> U,
+    {
+        let chunks = self.as_chunk_slice::<C>();
+
+        GenericArray::generate(|i| f(&chunks[i]))
+    }
+
+    /// Splits interleaved data into `K` channels, moving element `i` to
+    /// position `i / K` of channel `i % K`.
+    ///
+    /// The length of the array must be a multiple of `K`.
+    pub fn deinterleave_n<K>(self) -> GenericArray<GenericArray<T, PartialQuot<N, K>>, K>
+    where
+        N: PartialDiv<K>,
+        PartialQuot<N, K>: ArrayLength<T>,
+        K: ArrayLength<GenericArray<T, PartialQuot<N, K>>>,
+    {
+        let source = ManuallyDrop::new(self);
+
+        // Every element is read exactly once, and nothing here can panic.
+        GenericArray::generate(|k| {
+            GenericArray::generate(|i| unsafe {
+                ptr::read(source.get_unchecked(i * K::to_usize() + k))
+            })
+        })
+    }
+
+    /// Splits the array into its even-indexed and odd-indexed elements,
+    /// keeping their relative order.
+    ///
+    /// For odd `N` the even half holds one more element than the odd half.
+    ///
+    /// Example:
+    ///
+    /// ```ignore
+    /// let (even, odd) = arr![i32; 0, 1, 2, 3, 4].split_parity();
+    ///
+    /// assert_eq!(even, arr![i32; 0, 2, 4]);
+    /// assert_eq!(odd, arr![i32; 1, 3]);
+    /// ```
+    pub fn split_parity(self) -> SplitParity<T, N>
+    where
+        N: Add<B1> + Div<U2>,
+        Add1<N>: Div<U2>,
+        Quot<Add1<N>, U2>: ArrayLength<T>,
+        Quot<N, U2>: ArrayLength<T>,
+    {
+        let source = ManuallyDrop::new(self);
+
+        // Every element is read exactly once, and nothing here can panic.
+        let even = GenericArray::generate(|i| unsafe { ptr::read(source.get_unchecked(2 * i)) });
+        let odd = GenericArray::generate(|i| unsafe { ptr::read(source.get_unchecked(2 * i + 1)) });
+
+        (even, odd)
+    }
+
+    /// Moves the `N / C` consecutive chunks of length `C` out of the array one at a time.
+    ///
+    /// The length of the array must be a multiple of `C`. Any chunks not yielded
+    /// are dropped along with the iterator.
+    pub fn into_chunk_iter<C>(self) -> GenericArrayIter<GenericArray<T, C>, PartialQuot<N, C>>
+    where
+        C: ArrayLength<T>,
+        N: PartialDiv<C>,
+        PartialQuot<N, C>: ArrayLength<GenericArray<T, C>>,
+    {
+        // The chunks exactly cover the original array.
+        let chunks: GenericArray<GenericArray<T, C>, PartialQuot<N, C>> =
+            unsafe { transmute(self) };
+
+        chunks.into_iter()
+    }
+
+    /// Splits the array into `N / C` consecutive sub-arrays of length `C`,
+    /// checking at runtime that the length of the array is a multiple of `C`.
+    ///
+    /// Returns the array unchanged if it is not.
+    pub fn into_chunks_checked<C>(self) -> Result<Chunks<T, N, C>, Self>
+    where
+        C: ArrayLength<T>,
+        N: Div<C>,
+        Quot<N, C>: ArrayLength<GenericArray<T, C>>,
+    {
+        if N::to_usize() % C::to_usize() == 0 {
+            // The chunks exactly cover the original array.
+            Ok(unsafe { transmute(self) })
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Reverses the order of the `N / C` consecutive blocks of length `C`,
+    /// keeping the order of the elements within each block.
+    ///
+    /// The length of the array must be a multiple of `C`.
+    pub fn reverse_chunks<C>(&mut self)
+    where
+        C: ArrayLength<T>,
+        N: PartialDiv<C>,
+    {
+        let chunk = C::to_usize();
+        let mut rest = self.as_mut_slice();
+
+        while rest.len() >= 2 * chunk {
+            let (head, tail) = rest.split_at_mut(chunk);
+            let (middle, last) = tail.split_at_mut(tail.len() - chunk);
+
+            head.swap_with_slice(last);
+
+            rest = middle;
+        }
     }
 }
@@ -0,0 +1,94 @@
+//! Compile-time index patterns for shuffling the elements of a `GenericArray`.
+
+use super::{ArrayLength, GenericArray};
+use typenum::Unsigned;
+
+/// A fixed sequence of indices into an array of length `N`, used by `GenericArray::swizzle`.
+///
+/// Patterns are usually defined with the `swizzle_pattern!` macro.
+pub trait SwizzlePattern<N> {
+    /// Number of indices in the pattern
+    type Len: Unsigned;
+
+    /// Returns index `j` of the pattern, for `j` less than `Len`.
+    fn index(j: usize) -> usize;
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! swizzle_pattern_len {
+    () => ($crate::typenum::U0);
+    ($i1:expr $(, $i:expr)*) => (
+        $crate::typenum::Add1<swizzle_pattern_len!($($i),*)>
+    );
+}
+
+/// Macro defining a unit struct which implements `SwizzlePattern`.
+/// Example: `swizzle_pattern!(pub struct Zyx for U4 = [2, 1, 0]);`
+///
+/// Every index is checked against the array length at compile time:
+///
+/// ```compile_fail,E0080
+/// # #[macro_use]
+/// # extern crate generic_array;
+/// # use generic_array::typenum::U4;
+/// swizzle_pattern!(struct TooFar for U4 = [0, 7]);
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! swizzle_pattern {
+    ($(#[$attr:meta])* $vis:vis struct $name:ident for $N:ty = [$($i:expr),*]) => (
+        $(#[$attr])*
+        $vis struct $name;
+
+        // The array length underflows for an index which is out of bounds.
+        $(
+            const _: [(); 0 - !($i < <$N as $crate::typenum::Unsigned>::USIZE) as usize] = [];
+        )*
+
+        impl $crate::swizzle::SwizzlePattern<$N> for $name {
+            type Len = swizzle_pattern_len!($($i),*);
+
+            #[inline]
+            fn index(j: usize) -> usize {
+                const INDICES: &[usize] = &[$($i),*];
+
+                INDICES[j]
+            }
+        }
+    );
+}
+
+impl<T, N> GenericArray<T, N>
+where
+    N: ArrayLength<T>,
+{
+    /// Creates a new array whose element `j` is a clone of `self[P::index(j)]`.
+    ///
+    /// # Panics
+    ///
+    /// Patterns defined with `swizzle_pattern!` are checked at compile time.
+    /// A hand-written `SwizzlePattern` which returns an index out of bounds
+    /// of the array causes a panic.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate generic_array;
+    /// # use generic_array::typenum::U4;
+    /// swizzle_pattern!(struct Dba for U4 = [3, 1, 0]);
+    ///
+    /// # fn main() {
+    /// assert_eq!(arr![char; 'a', 'b', 'c', 'd'].swizzle::<Dba>(), arr![char; 'd', 'b', 'a']);
+    /// # }
+    /// ```
+    pub fn swizzle<P>(&self) -> GenericArray<T, P::Len>
+    where
+        T: Clone,
+        P: SwizzlePattern<N>,
+        P::Len: ArrayLength<T>,
+    {
+        GenericArray::generate(|j| self[P::index(j)].clone())
+    }
+}
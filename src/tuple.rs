@@ -0,0 +1,78 @@
+//! Conversions between short `GenericArray`s and tuples.
+
+use super::GenericArray;
+use typenum::consts::*;
+
+/// Defines `GenericArray`s which can be moved into a tuple of the same arity.
+pub trait ToTuple {
+    /// Tuple with one field per array element
+    type Tuple;
+
+    /// Moves the elements of the array into a tuple, in order.
+    /// Example:
+    ///
+    /// ```ignore
+    /// let (a, b, c) = arr![i32; 1, 2, 3].into_tuple();
+    ///
+    /// assert_eq!((a, b, c), (1, 2, 3));
+    /// ```
+    fn into_tuple(self) -> Self::Tuple;
+}
+
+/// Defines `GenericArray`s which can be built from a tuple of the same arity.
+pub trait FromTuple<Tuple> {
+    /// Moves the fields of a tuple into a new array, in order.
+    /// Example:
+    ///
+    /// ```ignore
+    /// let a = GenericArray::from_tuple((1, 2, 3, 4));
+    ///
+    /// assert_eq!(a, arr![i32; 1, 2, 3, 4]);
+    /// ```
+    fn from_tuple(tuple: Tuple) -> Self;
+}
+
+macro_rules! tuple_field {
+    ($x: ident) => {
+        T
+    };
+}
+
+macro_rules! impl_tuple {
+    ($($n: ty => ($($x: ident),+);)*) => {
+        $(
+            impl<T> ToTuple for GenericArray<T, $n> {
+                type Tuple = ($(tuple_field!($x),)+);
+
+                #[inline]
+                fn into_tuple(self) -> Self::Tuple {
+                    let [$($x),+]: [T; <$n as ::typenum::Unsigned>::USIZE] = self.into();
+
+                    ($($x,)+)
+                }
+            }
+
+            impl<T> FromTuple<($(tuple_field!($x),)+)> for GenericArray<T, $n> {
+                #[inline]
+                fn from_tuple(($($x,)+): ($(tuple_field!($x),)+)) -> Self {
+                    GenericArray::from([$($x),+])
+                }
+            }
+        )*
+    }
+}
+
+impl_tuple! {
+    U1 => (a);
+    U2 => (a, b);
+    U3 => (a, b, c);
+    U4 => (a, b, c, d);
+    U5 => (a, b, c, d, e);
+    U6 => (a, b, c, d, e, f);
+    U7 => (a, b, c, d, e, f, g);
+    U8 => (a, b, c, d, e, f, g, h);
+    U9 => (a, b, c, d, e, f, g, h, i);
+    U10 => (a, b, c, d, e, f, g, h, i, j);
+    U11 => (a, b, c, d, e, f, g, h, i, j, k);
+    U12 => (a, b, c, d, e, f, g, h, i, j, k, l);
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "base64")]
+
+#[macro_use]
+extern crate generic_array;
+extern crate typenum;
+
+use generic_array::{Base64Error, GenericArray};
+use typenum::{U0, U1, U2, U4, U5, U6};
+
+#[test]
+fn to_base64() {
+    assert_eq!(arr![u8; b'M', b'a', b'n', b'y'].to_base64(), "TWFueQ==");
+    assert_eq!(
+        arr![u8; b'h', b'e', b'l', b'l', b'o'].to_base64(),
+        "aGVsbG8="
+    );
+    assert_eq!(arr![u8; 1, 2, 3, 4, 5, 6].to_base64(), "AQIDBAUG");
+    assert_eq!(arr![u8;].to_base64(), "");
+}
+
+#[test]
+fn base64_round_trip() {
+    let ar = arr![u8; 0xFF, 0x00, 0x7F, 0x80, 0x3E];
+    assert_eq!(GenericArray::<u8, U5>::from_base64(&ar.to_base64()), Ok(ar));
+
+    let ar = arr![u8; 0xFB, 0xFF, 0xBF, 0x01, 0x02, 0x03];
+    assert_eq!(GenericArray::<u8, U6>::from_base64(&ar.to_base64()), Ok(ar));
+
+    assert_eq!(GenericArray::<u8, U0>::from_base64(""), Ok(arr![u8;]));
+}
+
+#[test]
+fn from_base64_wrong_length() {
+    assert_eq!(
+        GenericArray::<u8, U4>::from_base64("aGVsbG8="),
+        Err(Base64Error::WrongDecodedLength {
+            expected: 4,
+            actual: 5
+        })
+    );
+    assert_eq!(
+        GenericArray::<u8, U4>::from_base64("aGVsbG8"),
+        Err(Base64Error::InvalidLength)
+    );
+}
+
+#[test]
+fn from_base64_invalid_character() {
+    assert_eq!(
+        GenericArray::<u8, U5>::from_base64("aGV*bG8="),
+        Err(Base64Error::InvalidCharacter { index: 3 })
+    );
+    assert_eq!(
+        GenericArray::<u8, U4>::from_base64("TW=ueQ=="),
+        Err(Base64Error::InvalidCharacter { index: 2 })
+    );
+}
+
+#[test]
+fn from_base64_bad_padding() {
+    assert_eq!(
+        GenericArray::<u8, U2>::from_base64("Q=Q="),
+        Err(Base64Error::InvalidCharacter { index: 1 })
+    );
+    assert_eq!(
+        GenericArray::<u8, U1>::from_base64("Q==="),
+        Err(Base64Error::InvalidCharacter { index: 1 })
+    );
+}
@@ -2,9 +2,9 @@
 extern crate generic_array;
 extern crate typenum;
 
-use generic_array::GenericArray;
+use generic_array::{GenericArray, HexError};
 use std::str::from_utf8;
-use typenum::U2048;
+use typenum::{U2048, U3};
 
 
 #[test]
@@ -42,3 +42,52 @@ fn truncated_upper_hex() {
     let ar = arr![u8; 30, 20, 10, 17, 0];
     assert_eq!(format!("{:.4X}", ar), "1E140A11");
 }
+
+#[test]
+fn from_hex() {
+    let ar = GenericArray::<u8, U3>::from_hex("0a141E").unwrap();
+    assert_eq!(ar, arr![u8; 10, 20, 30]);
+}
+
+#[test]
+fn from_hex_invalid_length() {
+    assert_eq!(
+        GenericArray::<u8, U3>::from_hex("0a141"),
+        Err(HexError::InvalidLength {
+            expected: 6,
+            actual: 5
+        })
+    );
+    assert_eq!(
+        GenericArray::<u8, U3>::from_hex("0a14"),
+        Err(HexError::InvalidLength {
+            expected: 6,
+            actual: 4
+        })
+    );
+}
+
+#[test]
+fn from_hex_invalid_character() {
+    assert_eq!(
+        GenericArray::<u8, U3>::from_hex("0a1g1e"),
+        Err(HexError::InvalidCharacter { index: 3 })
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn to_hex() {
+    let ar = arr![u8; 10, 20, 30, 255];
+    assert_eq!(ar.to_hex(), "0a141eff");
+    assert_eq!(GenericArray::from_hex(&ar.to_hex()), Ok(ar));
+    assert_eq!(ar.to_hex(), format!("{:x}", ar));
+    assert_eq!(arr![u8;].to_hex(), "");
+}
+
+#[test]
+fn hex_debug() {
+    let ar = arr![u8; 10, 20, 30, 255];
+    assert_eq!(format!("{:?}", ar.hex_debug()), "0a141eff");
+    assert_eq!(format!("{:?}", ar), "[10, 20, 30, 255]");
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "std")]
+
+#[macro_use]
+extern crate generic_array;
+extern crate typenum;
+
+use generic_array::GenericArray;
+use std::io;
+use typenum::{U0, U3};
+
+#[test]
+fn read_from() {
+    let mut exact: &[u8] = b"abc";
+    let a = GenericArray::<u8, U3>::read_from(&mut exact).unwrap();
+
+    assert_eq!(a, arr![u8; b'a', b'b', b'c']);
+    assert!(exact.is_empty());
+
+    let mut short: &[u8] = b"ab";
+    let err = GenericArray::<u8, U3>::read_from(&mut short).unwrap_err();
+
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+    let mut extra: &[u8] = b"abcdef";
+    let a = GenericArray::<u8, U3>::read_from(&mut extra).unwrap();
+
+    assert_eq!(a, arr![u8; b'a', b'b', b'c']);
+    assert_eq!(extra, b"def");
+
+    let mut empty: &[u8] = b"";
+
+    assert_eq!(
+        GenericArray::<u8, U0>::read_from(&mut empty).unwrap(),
+        arr![u8;]
+    );
+}
+
+#[test]
+fn write_to() {
+    let mut out = Vec::new();
+
+    arr![u8; 1, 2, 3].write_to(&mut out).unwrap();
+    arr![u8; 4].write_to(&mut out).unwrap();
+
+    assert_eq!(out, [1, 2, 3, 4]);
+
+    let mut full = [0u8; 2];
+    let err = arr![u8; 1, 2, 3].write_to(&mut &mut full[..]).unwrap_err();
+
+    assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+}
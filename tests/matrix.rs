@@ -0,0 +1,270 @@
+#[macro_use]
+extern crate generic_array;
+extern crate typenum;
+
+use generic_array::GenericArray;
+use std::cell::Cell;
+use std::panic;
+use typenum::{U0, U1, U2, U3, U4, U5, U6};
+
+#[test]
+fn test_as_flat() {
+    let m: GenericArray<GenericArray<i32, U3>, U2> =
+        arr![GenericArray<i32, U3>; arr![i32; 1, 2, 3], arr![i32; 4, 5, 6]];
+
+    let flat = m.as_flat();
+
+    assert_eq!(flat, &arr![i32; 1, 2, 3, 4, 5, 6]);
+
+    for r in 0..2 {
+        for c in 0..3 {
+            assert_eq!(flat[r * 3 + c], m[r][c]);
+        }
+    }
+}
+
+#[test]
+fn test_as_flat_mut() {
+    let mut m: GenericArray<GenericArray<i32, U3>, U2> = GenericArray::default();
+
+    for (i, x) in m.as_flat_mut().iter_mut().enumerate() {
+        *x = i as i32;
+    }
+
+    assert_eq!(m[0], arr![i32; 0, 1, 2]);
+    assert_eq!(m[1], arr![i32; 3, 4, 5]);
+}
+
+#[test]
+fn test_transpose_map() {
+    let m: GenericArray<GenericArray<String, U3>, U2> =
+        GenericArray::generate(|r| GenericArray::generate(|c| format!("{}{}", r, c)));
+
+    let t = m.transpose_map(|s| s.clone());
+
+    assert_eq!(t.len(), 3);
+
+    for c in 0..3 {
+        for r in 0..2 {
+            assert_eq!(t[c][r], m[r][c]);
+        }
+    }
+
+    assert_eq!(t[2][1], "12");
+}
+
+#[test]
+fn test_transpose() {
+    let m: GenericArray<GenericArray<String, U5>, U3> =
+        GenericArray::generate(|r| GenericArray::generate(|c| format!("{}{}", r, c)));
+
+    let t = m.transpose();
+
+    assert_eq!(t.len(), 5);
+
+    for c in 0..5 {
+        assert_eq!(t[c].len(), 3);
+
+        for r in 0..3 {
+            assert_eq!(t[c][r], format!("{}{}", r, c));
+        }
+    }
+
+    let t = t.transpose();
+
+    assert_eq!(t[2][4], "24");
+
+    let empty: GenericArray<GenericArray<String, U0>, U3> = GenericArray::default();
+
+    assert_eq!(empty.transpose().len(), 0);
+}
+
+/// Element type which counts how many times it has been dropped.
+struct Counted<'a>(usize, &'a Cell<usize>);
+
+impl<'a> Drop for Counted<'a> {
+    fn drop(&mut self) {
+        self.1.set(self.1.get() + 1);
+    }
+}
+
+#[test]
+fn test_transpose_drops() {
+    let drop_counter = Cell::new(0);
+
+    {
+        let m: GenericArray<GenericArray<Counted, U5>, U3> = GenericArray::generate(|r| {
+            GenericArray::generate(|c| Counted(r * 5 + c, &drop_counter))
+        });
+
+        let t = m.transpose();
+
+        assert_eq!(drop_counter.get(), 0);
+
+        for c in 0..5 {
+            for r in 0..3 {
+                assert_eq!(t[c][r].0, r * 5 + c);
+            }
+        }
+    }
+
+    assert_eq!(drop_counter.get(), 15);
+}
+
+#[test]
+fn test_is_symmetric() {
+    let m: GenericArray<GenericArray<i32, U3>, U3> = arr![GenericArray<i32, U3>;
+        arr![i32; 1, 2, 3],
+        arr![i32; 2, 5, 6],
+        arr![i32; 3, 6, 9]];
+
+    assert!(m.is_symmetric());
+
+    let mut n = m;
+    n[0][2] = 4;
+
+    assert!(!n.is_symmetric());
+}
+
+#[test]
+fn test_mul_vec() {
+    let m: GenericArray<GenericArray<i32, U3>, U2> =
+        arr![GenericArray<i32, U3>; arr![i32; 1, 2, 3], arr![i32; 4, 5, 6]];
+
+    assert_eq!(m.mul_vec(&arr![i32; 1, 0, -1]), arr![i32; -2, -2]);
+
+    let identity: GenericArray<GenericArray<i32, U2>, U2> =
+        arr![GenericArray<i32, U2>; arr![i32; 1, 0], arr![i32; 0, 1]];
+
+    assert_eq!(identity.mul_vec(&arr![i32; 7, 8]), arr![i32; 7, 8]);
+
+    let empty: GenericArray<GenericArray<i32, U0>, U2> = GenericArray::default();
+
+    assert_eq!(empty.mul_vec(&arr![i32;]), arr![i32; 0, 0]);
+}
+
+#[test]
+fn test_map2d() {
+    let m: GenericArray<GenericArray<i32, U3>, U2> = GenericArray::default();
+
+    let n = m.map2d(|r, c, &x| x + (r * 10 + c) as i32);
+
+    assert_eq!(n[0], arr![i32; 0, 1, 2]);
+    assert_eq!(n[1], arr![i32; 10, 11, 12]);
+}
+
+#[test]
+fn test_reshape() {
+    let m: GenericArray<GenericArray<i32, U6>, U2> =
+        GenericArray::generate(|r| GenericArray::generate(|c| (r * 6 + c) as i32));
+
+    let n: GenericArray<GenericArray<i32, U4>, U3> = m.reshape();
+
+    assert_eq!(n[0], arr![i32; 0, 1, 2, 3]);
+    assert_eq!(n[1], arr![i32; 4, 5, 6, 7]);
+    assert_eq!(n[2], arr![i32; 8, 9, 10, 11]);
+
+    let back: GenericArray<GenericArray<i32, U6>, U2> = n.reshape();
+
+    assert_eq!(back, m);
+}
+
+#[test]
+fn test_try_reshape() {
+    let m: GenericArray<GenericArray<String, U6>, U2> =
+        GenericArray::generate(|r| GenericArray::generate(|c| format!("{}{}", r, c)));
+
+    let n = m.try_reshape::<U3, U4>().unwrap();
+
+    assert_eq!(n[1][0], "04");
+    assert_eq!(n[2][3], "15");
+
+    let err = n.try_reshape::<U3, U5>().unwrap_err();
+
+    assert_eq!(err[2][3], "15");
+}
+
+#[test]
+fn test_windows_collect() {
+    let m = arr![i32; 1, 2, 3, 4].windows_collect::<U2>();
+
+    assert_eq!(m.len(), 3);
+    assert_eq!(m[0], arr![i32; 1, 2]);
+    assert_eq!(m[1], arr![i32; 2, 3]);
+    assert_eq!(m[2], arr![i32; 3, 4]);
+
+    let all: GenericArray<GenericArray<i32, U4>, _> = arr![i32; 1, 2, 3, 4].windows_collect();
+
+    assert_eq!(all.len(), 1);
+}
+
+#[test]
+fn test_fold2d() {
+    let m: GenericArray<GenericArray<i32, U3>, U2> =
+        arr![GenericArray<i32, U3>; arr![i32; 1, -2, 3], arr![i32; 4, 0, -1]];
+
+    assert_eq!(m.fold2d(0, |acc, _, _, x| acc + x * x), 31);
+
+    let visited = m.fold2d(Vec::new(), |mut acc, r, c, x| {
+        acc.push((r, c, x));
+        acc
+    });
+
+    assert_eq!(visited[..3], [(0, 0, 1), (0, 1, -2), (0, 2, 3)]);
+    assert_eq!(visited[5], (1, 2, -1));
+
+    let no_rows: GenericArray<GenericArray<i32, U3>, U0> = GenericArray::default();
+    let no_cols: GenericArray<GenericArray<i32, U0>, U2> = GenericArray::default();
+
+    assert_eq!(no_rows.fold2d(7, |_, _, _, _| unreachable!()), 7);
+    assert_eq!(no_cols.fold2d(7, |_, _, _, _| unreachable!()), 7);
+}
+
+#[test]
+fn test_fold2d_panic_drops_remaining() {
+    let drop_counter = Cell::new(0);
+
+    let m: GenericArray<GenericArray<Counted, U3>, U2> =
+        GenericArray::generate(|r| GenericArray::generate(|c| Counted(r * 3 + c, &drop_counter)));
+
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        m.fold2d((), |_, r, c, _| {
+            if (r, c) == (1, 0) {
+                panic!("stop");
+            }
+        })
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(drop_counter.get(), 6);
+}
+
+#[test]
+fn test_outer() {
+    let m = arr![i32; 1, 2].outer(&arr![i32; 3, 4, 5]);
+
+    assert_eq!(m[0], arr![i32; 3, 4, 5]);
+    assert_eq!(m[1], arr![i32; 6, 8, 10]);
+
+    let zero = arr![i32; 0, 0, 0].outer(&arr![i32; 7, -8]);
+
+    assert_eq!(zero, GenericArray::<GenericArray<i32, U2>, U3>::default());
+}
+
+#[test]
+fn test_identity() {
+    let i: GenericArray<GenericArray<i32, U3>, U3> = GenericArray::identity();
+
+    assert_eq!(i[0], arr![i32; 1, 0, 0]);
+    assert_eq!(i[1], arr![i32; 0, 1, 0]);
+    assert_eq!(i[2], arr![i32; 0, 0, 1]);
+    assert_eq!(i.mul_vec(&arr![i32; 4, 5, 6]), arr![i32; 4, 5, 6]);
+
+    let one: GenericArray<GenericArray<f64, U1>, U1> = GenericArray::identity();
+
+    assert_eq!(one[0][0], 1.0);
+
+    let empty: GenericArray<GenericArray<f64, U0>, U0> = GenericArray::identity();
+
+    assert_eq!(empty.len(), 0);
+}
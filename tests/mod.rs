@@ -1,5 +1,7 @@
 #![recursion_limit="128"]
 #![no_std]
+#[cfg(feature = "alloc")]
+extern crate alloc;
 #[macro_use]
 extern crate generic_array;
 use core::cell::Cell;
@@ -256,4 +258,1733 @@ fn test_concat() {
 
     assert_eq!(d, arr![i32; 1]);
     assert_eq!(e, arr![i32; 2, 3, 4]);
-}
\ No newline at end of file
+}
+
+/// Element type which counts how many times it has been dropped.
+#[derive(Debug)]
+struct Counted<'a>(i32, &'a Cell<u32>);
+
+impl<'a> Drop for Counted<'a> {
+    fn drop(&mut self) {
+        self.1.set(self.1.get() + 1);
+    }
+}
+
+// Counted values compare by their value alone, ignoring the counter.
+impl<'a> PartialEq for Counted<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<'a> Eq for Counted<'a> {}
+
+impl<'a> PartialOrd for Counted<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for Counted<'a> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+#[test]
+fn test_into_array() {
+    let a: [u8; 4] = arr![u8; 1, 2, 3, 4].into();
+    assert_eq!(a, [1, 2, 3, 4]);
+
+    let drop_counter = Cell::new(0);
+    {
+        let ga = arr![Counted; Counted(1, &drop_counter), Counted(2, &drop_counter),
+                               Counted(3, &drop_counter), Counted(4, &drop_counter)];
+        let a: [Counted; 4] = ga.into();
+        assert_eq!(drop_counter.get(), 0);
+        assert_eq!(a[3].0, 4);
+    }
+    assert_eq!(drop_counter.get(), 4);
+}
+
+#[test]
+fn test_empty_array_alignment() {
+    use core::mem;
+    use generic_array::typenum::U0;
+
+    assert_eq!(mem::size_of::<GenericArray<u64, U0>>(), 0);
+    assert_eq!(
+        mem::align_of::<GenericArray<u64, U0>>(),
+        mem::align_of::<u64>()
+    );
+}
+
+#[test]
+fn test_rsplit() {
+    use generic_array::typenum::{U0, U5};
+
+    let a = arr![i32; 1, 2, 3, 4, 5];
+
+    let (init, tail) = a.rsplit::<U3>();
+
+    assert_eq!(init, arr![i32; 1, 2]);
+    assert_eq!(tail, arr![i32; 3, 4, 5]);
+
+    let (init, tail) = a.rsplit::<U0>();
+
+    assert_eq!(init, a);
+    assert_eq!(tail, arr![i32;]);
+
+    let (init, tail) = a.rsplit::<U5>();
+
+    assert_eq!(init, arr![i32;]);
+    assert_eq!(tail, a);
+}
+
+#[test]
+fn test_fill() {
+    let mut a = arr![u8; 1, 2, 3, 4];
+
+    a.fill(7);
+
+    assert_eq!(a, arr![u8; 7, 7, 7, 7]);
+
+    let mut e = arr![u8;];
+
+    e.fill(7);
+
+    assert_eq!(e, arr![u8;]);
+}
+
+#[test]
+fn test_split_array_ref() {
+    let a = arr![i32; 1, 2, 3, 4, 5];
+
+    let (head, tail) = a.split_array_ref::<U3>();
+
+    assert_eq!(head, &arr![i32; 1, 2, 3]);
+    assert_eq!(head.as_ptr(), a.as_ptr());
+    assert_eq!(tail, &a[3..]);
+}
+
+#[test]
+fn test_sum_product() {
+    assert_eq!(arr![i32; 1, 2, 3, 4].sum(), 10);
+    assert_eq!(arr![i32; 1, 2, 3, 4].product(), 24);
+
+    assert_eq!(arr![i32;].sum(), 0);
+    assert_eq!(arr![i32;].product(), 1);
+}
+
+#[test]
+fn test_checked_sum() {
+    assert_eq!(arr![u8; 100, 100, 55].checked_sum(), Some(255));
+    assert_eq!(arr![u8; 100, 100, 56].checked_sum(), None);
+    assert_eq!(arr![u8;].checked_sum(), Some(0));
+}
+
+#[cfg(feature = "alloc")]
+mod impl_alloc {
+    use super::Counted;
+    use alloc::vec::Vec;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_map_collect_errors() {
+        let ok: Result<_, Vec<i32>> = arr![i32; 1, 2, 3].map_collect_errors(|x| Ok(x * 2));
+        assert_eq!(ok, Ok(arr![i32; 2, 4, 6]));
+
+        let err =
+            arr![i32; 1, -2, 3, -4].map_collect_errors(|x| if x > 0 { Ok(x) } else { Err(x) });
+        assert_eq!(err, Err(alloc::vec![-2, -4]));
+
+        let drop_counter = Cell::new(0);
+        let err = arr![i32; 1, 2, -3, 4].map_collect_errors(|x| {
+            if x > 0 {
+                Ok(Counted(x, &drop_counter))
+            } else {
+                Err(x)
+            }
+        });
+        assert_eq!(err.unwrap_err(), alloc::vec![-3]);
+        assert_eq!(drop_counter.get(), 3);
+    }
+
+    #[test]
+    fn test_concat_with_slice() {
+        let a = arr![i32; 1, 2];
+
+        assert_eq!(a.concat_with_slice(&[]), alloc::vec![1, 2]);
+        assert_eq!(a.concat_with_slice(&[3, 4, 5]), alloc::vec![1, 2, 3, 4, 5]);
+        assert_eq!(a, arr![i32; 1, 2]);
+    }
+
+    #[test]
+    fn test_from_boxed_slice() {
+        use alloc::boxed::Box;
+        use generic_array::GenericArray;
+        use generic_array::typenum::U3;
+
+        let b: Box<[i32]> = alloc::vec![1, 2, 3].into_boxed_slice();
+        assert_eq!(
+            GenericArray::<i32, U3>::from_boxed_slice(b),
+            Ok(arr![i32; 1, 2, 3])
+        );
+
+        let b: Box<[i32]> = alloc::vec![1, 2].into_boxed_slice();
+        assert_eq!(
+            GenericArray::<i32, U3>::from_boxed_slice(b),
+            Err(alloc::vec![1, 2].into_boxed_slice())
+        );
+
+        let drop_counter = Cell::new(0);
+        {
+            let b: Box<[Counted]> = (0..3).map(|i| Counted(i, &drop_counter)).collect();
+            let a = GenericArray::<Counted, U3>::from_boxed_slice(b)
+                .ok()
+                .unwrap();
+            assert_eq!(drop_counter.get(), 0);
+            assert_eq!(a[2].0, 2);
+
+            let b: Box<[Counted]> = (0..2).map(|i| Counted(i, &drop_counter)).collect();
+            let b = GenericArray::<Counted, U3>::from_boxed_slice(b)
+                .err()
+                .unwrap();
+            assert_eq!(drop_counter.get(), 0);
+            assert_eq!(b.len(), 2);
+        }
+        assert_eq!(drop_counter.get(), 5);
+    }
+
+    #[test]
+    fn test_into_vec_from_vec() {
+        use alloc::string::{String, ToString};
+        use generic_array::GenericArray;
+        use generic_array::typenum::{U0, U3};
+
+        let v: Vec<String> = alloc::vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let a = GenericArray::<String, U3>::from_vec(v.clone()).unwrap();
+        assert_eq!(a[1], "b");
+        assert_eq!(a.into_vec(), v);
+
+        let short = alloc::vec!["a".to_string()];
+        assert_eq!(
+            GenericArray::<String, U3>::from_vec(short.clone()),
+            Err(short)
+        );
+
+        let empty = GenericArray::<String, U0>::from_vec(Vec::new()).unwrap();
+        assert_eq!(empty.into_vec(), Vec::<String>::new());
+
+        let drop_counter = Cell::new(0);
+        {
+            let v: Vec<Counted> = (0..3).map(|i| Counted(i, &drop_counter)).collect();
+            let a = GenericArray::<Counted, U3>::from_vec(v).ok().unwrap();
+            let v = a.into_vec();
+            assert_eq!(drop_counter.get(), 0);
+            assert_eq!(v[2].0, 2);
+        }
+        assert_eq!(drop_counter.get(), 3);
+    }
+
+    #[test]
+    fn test_positions() {
+        let a = arr![i32; 4, 1, 6, 3, 8];
+
+        assert_eq!(a.positions(|&x| x % 2 == 0), alloc::vec![0, 2, 4]);
+        assert_eq!(a.positions(|&x| x > 10), Vec::<usize>::new());
+        assert_eq!(a.positions(|_| true), (0..5).collect::<Vec<_>>());
+        assert_eq!(arr![i32;].positions(|_| true), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_dedup_to_vec() {
+        use generic_array::GenericArray;
+        use generic_array::typenum::U6;
+
+        assert_eq!(
+            arr![i32; 1, 1, 2, 3, 3, 3, 1].dedup_to_vec(),
+            alloc::vec![1, 2, 3, 1]
+        );
+        assert_eq!(arr![i32; 1, 2, 3].dedup_to_vec(), alloc::vec![1, 2, 3]);
+        assert_eq!(arr![i32; 4, 4, 4, 4].dedup_to_vec(), alloc::vec![4]);
+        assert_eq!(arr![i32;].dedup_to_vec(), Vec::<i32>::new());
+
+        let drop_counter = Cell::new(0);
+        {
+            let a: GenericArray<Counted, U6> =
+                GenericArray::generate(|i| Counted([1, 1, 2, 2, 2, 3][i], &drop_counter));
+            let v = a.dedup_to_vec();
+            assert_eq!(drop_counter.get(), 3);
+            assert_eq!(
+                v.iter().map(|c| c.0).collect::<Vec<_>>(),
+                alloc::vec![1, 2, 3]
+            );
+        }
+        assert_eq!(drop_counter.get(), 6);
+    }
+}
+
+#[test]
+fn test_as_chunk_slice() {
+    use generic_array::typenum::{U2, U6};
+
+    let a: GenericArray<i32, U6> = arr![i32; 1, 2, 3, 4, 5, 6];
+
+    let chunks = a.as_chunk_slice::<U2>();
+
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks[1], arr![i32; 3, 4]);
+    assert_eq!(chunks[2].as_ptr(), a[4..].as_ptr());
+}
+
+#[test]
+fn test_reverse_chunks() {
+    use generic_array::typenum::U2;
+
+    let mut a = arr![char; 'a', 'b', 'c', 'd', 'e', 'f'];
+
+    a.reverse_chunks::<U2>();
+
+    assert_eq!(a, arr![char; 'e', 'f', 'c', 'd', 'a', 'b']);
+
+    let mut b = arr![i32; 1, 2, 3];
+
+    b.reverse_chunks::<U3>();
+
+    assert_eq!(b, arr![i32; 1, 2, 3]);
+}
+
+#[test]
+fn test_saturating_wrapping() {
+    let a = arr![u8; 250, 100, 255, 0];
+    let b = arr![u8; 10, 100, 1, 1];
+
+    assert_eq!(a.saturating_add(b), arr![u8; 255, 200, 255, 1]);
+    assert_eq!(a.wrapping_add(b), arr![u8; 4, 200, 0, 1]);
+    assert_eq!(a.saturating_sub(b), arr![u8; 240, 0, 254, 0]);
+    assert_eq!(a.wrapping_sub(b), arr![u8; 240, 0, 254, 255]);
+    assert_eq!(a.saturating_mul(b), arr![u8; 255, 255, 255, 0]);
+    assert_eq!(a.wrapping_mul(b), arr![u8; 196, 16, 255, 0]);
+}
+
+#[test]
+fn test_is_sorted() {
+    assert!(arr![i32; 1, 2, 3, 4].is_sorted());
+    assert!(!arr![i32; 1, 3, 2, 4].is_sorted());
+    assert!(arr![i32; 1, 2, 2, 4].is_sorted());
+    assert!(arr![i32; 1].is_sorted());
+    assert!(arr![i32;].is_sorted());
+
+    assert!(arr![i32; 4, 3, 1].is_sorted_by(|a, b| b.partial_cmp(a)));
+    assert!(!arr![f32; 1.0, f32::NAN].is_sorted());
+}
+
+#[test]
+fn test_uninit() {
+    use core::mem::MaybeUninit;
+    use core::ptr;
+
+    unsafe fn fill(buf: *mut i32, len: usize) {
+        for i in 0..len {
+            ptr::write(buf.add(i), i as i32 * 2);
+        }
+    }
+
+    let mut a = GenericArray::<i32, U4>::uninit();
+
+    unsafe { fill(a.as_mut_ptr() as *mut i32, a.len()) };
+
+    let a = unsafe { GenericArray::assume_init(a) };
+
+    assert_eq!(a, arr![i32; 0, 2, 4, 6]);
+
+    let drop_counter = Cell::new(0);
+    let mut b = arr![Counted; Counted(1, &drop_counter), Counted(2, &drop_counter)];
+
+    unsafe {
+        let old = ptr::read(b.as_uninit_mut()[0].as_ptr());
+        b.as_uninit_mut()[0] = MaybeUninit::new(Counted(3, &drop_counter));
+        drop(old);
+    }
+
+    assert_eq!(drop_counter.get(), 1);
+    assert_eq!(b[0].0, 3);
+}
+
+#[test]
+fn test_enumerate() {
+    use generic_array::ArrayLength;
+
+    fn weighted<N: ArrayLength<i32>>(a: &GenericArray<i32, N>) -> i32 {
+        a.enumerate().map(|(i, x)| i as i32 * x).sum()
+    }
+
+    let mut a = arr![i32; 5, 6, 7];
+
+    assert_eq!(weighted(&a), 20);
+
+    for (i, x) in a.enumerate_mut() {
+        *x += i as i32;
+    }
+
+    assert_eq!(a, arr![i32; 5, 7, 9]);
+}
+
+#[test]
+fn test_first_last() {
+    use generic_array::ArrayLength;
+    use generic_array::typenum::NonZero;
+
+    fn ends<N: ArrayLength<i32>>(a: &GenericArray<i32, N>) -> (Option<&i32>, Option<&i32>) {
+        (a.first(), a.last())
+    }
+
+    fn head<N: ArrayLength<i32> + NonZero>(a: &GenericArray<i32, N>) -> i32 {
+        *a.first_typed()
+    }
+
+    let mut a = arr![i32; 1, 2, 3];
+
+    assert_eq!(ends(&a), (Some(&1), Some(&3)));
+    assert_eq!(ends(&arr![i32;]), (None, None));
+    assert_eq!(head(&a), 1);
+
+    *a.first_mut().unwrap() = 10;
+    *a.last_mut().unwrap() = 30;
+
+    assert_eq!(a, arr![i32; 10, 2, 30]);
+}
+
+#[test]
+fn test_rposition_rfind() {
+    let a = arr![i32; 1, 2, 3, 2, 5];
+
+    assert_eq!(a.rposition(|&x| x == 2), Some(3));
+    assert_eq!(a.rposition(|&x| x == 5), Some(4));
+    assert_eq!(a.rposition(|&x| x == 7), None);
+
+    assert_eq!(a.rfind(|&x| x < 4), Some(&2));
+    assert_eq!(a.rfind(|&x| x > 7), None);
+}
+
+#[test]
+fn test_split_off() {
+    use generic_array::typenum::{U0, U2, U5};
+
+    let a = arr![i32; 1, 2, 3, 4, 5];
+
+    let (kept, removed) = a.split_off::<U2>();
+
+    assert_eq!(kept, arr![i32; 1, 2, 3]);
+    assert_eq!(removed, arr![i32; 4, 5]);
+
+    let (kept, removed) = a.split_off::<U0>();
+
+    assert_eq!(kept, a);
+    assert_eq!(removed, arr![i32;]);
+
+    let (kept, removed) = a.split_off::<U5>();
+
+    assert_eq!(kept, arr![i32;]);
+    assert_eq!(removed, a);
+}
+
+#[test]
+fn test_gather() {
+    let a = arr![char; 'a', 'b', 'c', 'd'];
+
+    assert_eq!(
+        a.gather(&arr![usize; 3, 2, 1, 0]),
+        Some(arr![char; 'd', 'c', 'b', 'a'])
+    );
+    assert_eq!(
+        a.gather(&arr![usize; 1, 1, 1]),
+        Some(arr![char; 'b', 'b', 'b'])
+    );
+    assert_eq!(a.gather(&arr![usize; 0, 4]), None);
+}
+
+#[test]
+fn test_scatter() {
+    use generic_array::ScatterError;
+
+    let mut a = arr![i32; 0, 0, 0, 0];
+
+    assert_eq!(a.scatter(&arr![usize; 3, 0], arr![i32; 1, 2]), Ok(()));
+    assert_eq!(a, arr![i32; 2, 0, 0, 1]);
+
+    assert_eq!(
+        a.scatter(&arr![usize; 1, 4], arr![i32; 5, 6]),
+        Err(ScatterError::OutOfBounds { index: 4 })
+    );
+    assert_eq!(
+        a.scatter(&arr![usize; 1, 2, 1], arr![i32; 5, 6, 7]),
+        Err(ScatterError::Duplicate { index: 1 })
+    );
+    assert_eq!(a, arr![i32; 2, 0, 0, 1]);
+}
+
+#[test]
+fn test_windows_fold() {
+    let a = arr![i32; 3, 6, 9, 3, 0, 6, 3];
+
+    let averages = a.windows_fold::<U3, _, _>(|w| w.iter().sum::<i32>() / 3);
+
+    assert_eq!(averages, arr![i32; 6, 6, 4, 3, 3]);
+}
+
+#[test]
+fn test_deinterleave_n() {
+    let a = arr![&str; "a0", "b0", "c0", "a1", "b1", "c1"];
+
+    let channels = a.deinterleave_n::<U3>();
+
+    assert_eq!(channels[0], arr![&str; "a0", "a1"]);
+    assert_eq!(channels[1], arr![&str; "b0", "b1"]);
+    assert_eq!(channels[2], arr![&str; "c0", "c1"]);
+
+    let interleaved = channels.transpose_map(|&s| s);
+
+    assert_eq!(interleaved.as_flat(), &a);
+}
+
+#[test]
+fn test_as_ascii_str() {
+    assert_eq!(arr![u8; b'G', b'E', b'T'].as_ascii_str(), Ok("GET"));
+    assert_eq!(arr![u8;].as_ascii_str(), Ok(""));
+
+    let err = arr![u8; b'o', b'k', 0xC3, 0xA9].as_ascii_str().unwrap_err();
+
+    assert_eq!(err.index(), 2);
+}
+
+#[test]
+fn test_rotate_left_const() {
+    use generic_array::typenum::{U0, U2};
+
+    let a = arr![i32; 1, 2, 3];
+
+    assert_eq!(a.rotate_left_const::<U0>(), a);
+    assert_eq!(a.rotate_left_const::<U1>(), arr![i32; 2, 3, 1]);
+
+    let mut b = a;
+    b.rotate_left(2);
+
+    assert_eq!(a.rotate_left_const::<U2>(), b);
+}
+
+#[test]
+fn test_try_map_with_index() {
+    let ok: Result<_, usize> = arr![i32; 1, 2, 3].try_map_with_index(|i, x| Ok(x * i as i32));
+    assert_eq!(ok, Ok(arr![i32; 0, 2, 6]));
+
+    let empty: Result<GenericArray<i32, _>, ()> = arr![i32;].try_map_with_index(|_, x| Ok(x));
+    assert_eq!(empty, Ok(arr![i32;]));
+
+    let drop_counter = Cell::new(0);
+    let source = arr![Counted; Counted(1, &drop_counter), Counted(2, &drop_counter),
+                               Counted(3, &drop_counter), Counted(4, &drop_counter)];
+
+    let err = source.try_map_with_index(|i, x| if i < 2 { Ok(x.0) } else { Err(i) });
+
+    assert_eq!(err, Err(2));
+    assert_eq!(drop_counter.get(), 4);
+}
+
+#[test]
+fn test_sub() {
+    use core::mem::size_of;
+    use generic_array::typenum::{U0, U2, U5};
+
+    let mut a = arr![u32; 1, 2, 3, 4, 5];
+
+    let middle = a.sub::<U2, U3>();
+
+    assert_eq!(middle, &arr![u32; 3, 4, 5]);
+    assert_eq!(
+        middle.as_ptr() as usize - a.as_ptr() as usize,
+        2 * size_of::<u32>()
+    );
+    assert_eq!(a.sub::<U0, U5>(), &a);
+
+    a.sub_mut::<U1, U2>()[1] = 10;
+
+    assert_eq!(a, arr![u32; 1, 2, 10, 4, 5]);
+}
+
+#[test]
+fn test_try_fold() {
+    let sum: Result<i32, ()> = arr![i32; 1, 2, 3].try_fold(0, |acc, x| Ok(acc + x));
+    assert_eq!(sum, Ok(6));
+
+    let empty: Result<i32, ()> = arr![i32;].try_fold(7, |acc, x| Ok(acc + x));
+    assert_eq!(empty, Ok(7));
+
+    let drop_counter = Cell::new(0);
+    let source = arr![Counted; Counted(1, &drop_counter), Counted(2, &drop_counter),
+                               Counted(3, &drop_counter), Counted(4, &drop_counter)];
+
+    let err = source.try_fold(0, |acc, x| if x.0 < 2 { Ok(acc + x.0) } else { Err(x.0) });
+
+    assert_eq!(err, Err(2));
+    assert_eq!(drop_counter.get(), 4);
+}
+
+#[test]
+fn test_fold_first() {
+    assert_eq!(arr![i32; 3, 7, 5].fold_first(core::cmp::max), Some(7));
+    assert_eq!(arr![i32;].fold_first(core::cmp::max), None);
+}
+
+#[test]
+fn test_from_fn_checked() {
+    let a = GenericArray::<usize, U3>::from_fn_checked(3, |i| i * i);
+    assert_eq!(a, Ok(arr![usize; 0, 1, 4]));
+
+    let err = GenericArray::<usize, U3>::from_fn_checked(4, |_| panic!("must not be called"));
+    let err = err.unwrap_err();
+    assert_eq!((err.expected(), err.actual()), (4, 3));
+}
+
+#[test]
+fn test_select() {
+    let a = arr![i32; 1, 2, 3, 4];
+    let b = arr![i32; -1, -2, -3, -4];
+
+    let checkerboard = arr![bool; true, false, true, false];
+
+    assert_eq!(
+        GenericArray::select(&checkerboard, a, b),
+        arr![i32; 1, -2, 3, -4]
+    );
+    assert_eq!(
+        GenericArray::select(&arr![bool; true, true, true, true], a, b),
+        a
+    );
+    assert_eq!(
+        GenericArray::select(&arr![bool; false, false, false, false], a, b),
+        b
+    );
+
+    let drop_counter = Cell::new(0);
+    {
+        let a = arr![Counted; Counted(1, &drop_counter), Counted(2, &drop_counter),
+                              Counted(3, &drop_counter), Counted(4, &drop_counter)];
+        let b = arr![Counted; Counted(-1, &drop_counter), Counted(-2, &drop_counter),
+                              Counted(-3, &drop_counter), Counted(-4, &drop_counter)];
+
+        let c = GenericArray::select(&checkerboard, a, b);
+
+        assert_eq!(drop_counter.get(), 4);
+        assert_eq!(c[1].0, -2);
+    }
+    assert_eq!(drop_counter.get(), 8);
+}
+
+#[test]
+fn test_pairwise() {
+    let a = arr![i32; 1, 3, 6, 10];
+
+    let diffs: GenericArray<i32, U3> = a.pairwise().map(|(x, y)| y - x).collect();
+
+    assert_eq!(diffs, arr![i32; 2, 3, 4]);
+    assert_eq!(arr![i32; 1].pairwise().count(), 0);
+    assert_eq!(arr![i32;].pairwise().count(), 0);
+}
+
+#[test]
+fn test_add_assign_checked() {
+    let mut a = arr![u8; 1, 2, 250, 4];
+
+    assert_eq!(a.add_assign_checked(&arr![u8; 1, 1, 1, 1]), Ok(()));
+    assert_eq!(a, arr![u8; 2, 3, 251, 5]);
+
+    let err = a.add_assign_checked(&arr![u8; 1, 1, 5, 1]).unwrap_err();
+
+    assert_eq!(err.index(), 2);
+    assert_eq!(a, arr![u8; 2, 3, 251, 5]);
+
+    let mut e = arr![u8;];
+
+    assert_eq!(e.add_assign_checked(&arr![u8;]), Ok(()));
+}
+
+#[test]
+fn test_try_from_results() {
+    use generic_array::TryCollectError;
+
+    let ok = GenericArray::<i32, U3>::try_from_results(
+        arr![Result<i32, ()>; Ok(1), Ok(2), Ok(3), Ok(4)],
+    );
+    assert_eq!(ok, Ok(arr![i32; 1, 2, 3]));
+
+    let err =
+        GenericArray::<i32, U3>::try_from_results(arr![Result<i32, i32>; Ok(1), Err(2), Ok(3)]);
+    assert_eq!(err, Err(TryCollectError::Item(2)));
+
+    let drop_counter = Cell::new(0);
+    let short = GenericArray::<Counted, U3>::try_from_results(
+        arr![Result<Counted, ()>; Ok(Counted(1, &drop_counter)), Ok(Counted(2, &drop_counter))],
+    );
+    assert_eq!(short.unwrap_err(), TryCollectError::TooShort(2));
+    assert_eq!(drop_counter.get(), 2);
+}
+
+#[test]
+fn test_swap_with_slice() {
+    let mut front = arr![i32; 1, 2, 3];
+    let mut back = [4, 5, 6];
+
+    front.swap_with_slice(&mut back);
+
+    assert_eq!(front, arr![i32; 4, 5, 6]);
+    assert_eq!(back, [1, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "slice length 2 != array length 3")]
+fn test_swap_with_slice_length_mismatch() {
+    arr![i32; 1, 2, 3].swap_with_slice(&mut [4, 5]);
+}
+
+#[test]
+fn test_zip_slice() {
+    let a = arr![i32; 1, 2, 3];
+
+    assert_eq!(
+        a.zip_slice(&[2, 3, 4], |x, y| x * y),
+        Some(arr![i32; 2, 6, 12])
+    );
+    assert_eq!(a.zip_slice(&[2, 3], |x, y| x * y), None);
+    assert_eq!(
+        arr![i32;].zip_slice(&[] as &[i32], |x, y| x * y),
+        Some(arr![i32;])
+    );
+}
+
+#[test]
+fn test_split_mut() {
+    let mut a = arr![i32; 1, 2, 3, 4, 5];
+
+    {
+        let (head, tail) = a.split_mut::<U3>();
+
+        head[0] = 10;
+        tail[1] = 50;
+
+        assert_eq!(head, &arr![i32; 10, 2, 3]);
+        assert_eq!(tail, &arr![i32; 4, 50]);
+    }
+
+    assert_eq!(a, arr![i32; 10, 2, 3, 4, 50]);
+}
+
+#[test]
+fn test_is_palindrome() {
+    assert!(arr![i32; 1, 2, 3, 2, 1].is_palindrome());
+    assert!(arr![i32; 1, 2, 2, 1].is_palindrome());
+    assert!(!arr![i32; 1, 2, 3, 1].is_palindrome());
+    assert!(arr![i32; 1].is_palindrome());
+    assert!(arr![i32;].is_palindrome());
+}
+
+#[test]
+fn test_zip_slice_eq() {
+    assert_eq!(
+        arr![i32; 1, 2].zip_slice_eq(&[3, 4], |x, y| x + y),
+        arr![i32; 4, 6]
+    );
+}
+
+#[test]
+#[should_panic(expected = "slice length 3 != array length 2")]
+fn test_zip_slice_eq_length_mismatch() {
+    arr![i32; 1, 2].zip_slice_eq(&[3, 4, 5], |x, y| x + y);
+}
+
+#[test]
+fn test_rotated_left() {
+    let a = arr![i32; 1, 2, 3, 4];
+
+    assert_eq!(a.rotated_left(0), a);
+    assert_eq!(a.rotated_left(4), a);
+    assert_eq!(a.rotated_left(5), arr![i32; 2, 3, 4, 1]);
+    assert_eq!(arr![i32;].rotated_left(3), arr![i32;]);
+
+    let drop_counter = Cell::new(0);
+    {
+        let b = arr![Counted; Counted(1, &drop_counter), Counted(2, &drop_counter),
+                              Counted(3, &drop_counter)];
+
+        let b = b.rotated_left(2);
+
+        assert_eq!(drop_counter.get(), 0);
+        assert_eq!((b[0].0, b[1].0, b[2].0), (3, 1, 2));
+    }
+    assert_eq!(drop_counter.get(), 3);
+}
+
+#[test]
+fn test_split_at_cycled() {
+    let a = arr![i32; 1, 2, 3, 4];
+
+    assert_eq!(a.split_at_cycled(0), (&[1, 2, 3, 4][..], &[][..]));
+    assert_eq!(a.split_at_cycled(4), (&[][..], &[1, 2, 3, 4][..]));
+    assert_eq!(a.split_at_cycled(1), (&[2, 3, 4][..], &[1][..]));
+}
+
+#[test]
+fn test_into_chunks_checked() {
+    use generic_array::typenum::U2;
+
+    let a = arr![i32; 1, 2, 3, 4, 5, 6];
+
+    let chunks = a.into_chunks_checked::<U2>().unwrap();
+
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks[2], arr![i32; 5, 6]);
+
+    let b = arr![i32; 1, 2, 3, 4, 5];
+
+    assert_eq!(b.into_chunks_checked::<U2>(), Err(b));
+}
+
+#[test]
+fn test_sum_as() {
+    assert_eq!(arr![u8; 200, 100, 255].sum_as::<u32>(), 555);
+    assert_eq!(arr![u8;].sum_as::<u32>(), 0);
+}
+
+#[test]
+fn test_copy_window() {
+    use generic_array::typenum::U2;
+
+    let buf = [1, 2, 3, 4, 5];
+
+    assert_eq!(
+        GenericArray::<i32, U2>::copy_window(&buf, 1),
+        Some(arr![i32; 2, 3])
+    );
+    assert_eq!(
+        GenericArray::<i32, U3>::copy_window(&buf, 2),
+        Some(arr![i32; 3, 4, 5])
+    );
+    assert_eq!(GenericArray::<i32, U2>::copy_window(&buf, 4), None);
+    assert_eq!(GenericArray::<i32, U2>::copy_window(&buf, 7), None);
+    assert_eq!(GenericArray::<i32, U2>::copy_window(&buf, usize::MAX), None);
+}
+
+#[test]
+fn test_try_zip_slices() {
+    use generic_array::ZipLengthError;
+
+    let sum = GenericArray::<i32, U3>::try_zip_slices(&[1, 2, 3], &[10u8, 20, 30], |a, b| {
+        a + i32::from(*b)
+    });
+    assert_eq!(sum, Ok(arr![i32; 11, 22, 33]));
+
+    let err =
+        GenericArray::<i32, U3>::try_zip_slices(&[1, 2], &[10u8, 20, 30], |a, b| a + i32::from(*b))
+            .unwrap_err();
+    match err {
+        ZipLengthError::First(e) => assert_eq!((e.expected(), e.actual()), (3, 2)),
+        ZipLengthError::Second(_) => panic!("wrong side: {:?}", err),
+    }
+
+    let err = GenericArray::<i32, U3>::try_zip_slices(&[1, 2, 3], &[10u8, 20, 30, 40], |a, b| {
+        a + i32::from(*b)
+    })
+    .unwrap_err();
+    match err {
+        ZipLengthError::Second(e) => assert_eq!((e.expected(), e.actual()), (3, 4)),
+        ZipLengthError::First(_) => panic!("wrong side: {:?}", err),
+    }
+}
+
+#[test]
+fn test_permute_in_place() {
+    use generic_array::PermutationError;
+    use generic_array::typenum::U5;
+
+    let mut a = arr![char; 'a', 'b', 'c', 'd', 'e'];
+    let mut reverse = arr![usize; 4, 3, 2, 1, 0];
+    assert_eq!(a.permute_in_place(&mut reverse), Ok(()));
+    assert_eq!(a, arr![char; 'e', 'd', 'c', 'b', 'a']);
+    assert_eq!(reverse, arr![usize; 4, 3, 2, 1, 0]);
+
+    let mut identity = arr![usize; 0, 1, 2, 3, 4];
+    assert_eq!(a.permute_in_place(&mut identity), Ok(()));
+    assert_eq!(a, arr![char; 'e', 'd', 'c', 'b', 'a']);
+
+    let mut a = arr![i32; 10, 20, 30, 40, 50];
+    let mut cycles = arr![usize; 2, 0, 1, 4, 3];
+    assert_eq!(a.permute_in_place(&mut cycles), Ok(()));
+    assert_eq!(a, arr![i32; 30, 10, 20, 50, 40]);
+
+    let mut duplicate: GenericArray<usize, U5> = arr![usize; 0, 1, 1, 3, 4];
+    assert_eq!(
+        a.permute_in_place(&mut duplicate),
+        Err(PermutationError::Duplicate { index: 1 })
+    );
+    assert_eq!(a, arr![i32; 30, 10, 20, 50, 40]);
+    assert_eq!(duplicate, arr![usize; 0, 1, 1, 3, 4]);
+
+    let mut out_of_bounds = arr![usize; 0, 1, 2, 3, 5];
+    assert_eq!(
+        a.permute_in_place(&mut out_of_bounds),
+        Err(PermutationError::OutOfBounds { index: 5 })
+    );
+    assert_eq!(a, arr![i32; 30, 10, 20, 50, 40]);
+    assert_eq!(out_of_bounds, arr![usize; 0, 1, 2, 3, 5]);
+
+    let mut b = arr![i32; 1, 2];
+
+    let mut top_bit_set = arr![usize; usize::MAX, 0];
+    assert_eq!(
+        b.permute_in_place(&mut top_bit_set),
+        Err(PermutationError::OutOfBounds { index: usize::MAX })
+    );
+    assert_eq!(top_bit_set, arr![usize; usize::MAX, 0]);
+
+    let top_bit = !(usize::MAX >> 1);
+    let mut marked_alias = arr![usize; 1, top_bit];
+    assert_eq!(
+        b.permute_in_place(&mut marked_alias),
+        Err(PermutationError::OutOfBounds { index: top_bit })
+    );
+    assert_eq!(marked_alias, arr![usize; 1, top_bit]);
+    assert_eq!(b, arr![i32; 1, 2]);
+}
+
+#[test]
+fn test_concat_into() {
+    use generic_array::typenum::U5;
+
+    let mut out = GenericArray::<i32, U5>::uninit();
+    arr![i32; 1, 2, 3].concat_into(arr![i32; 4, 5], &mut out);
+    assert_eq!(
+        unsafe { GenericArray::assume_init(out) },
+        arr![i32; 1, 2, 3, 4, 5]
+    );
+
+    let drop_counter = Cell::new(0);
+    {
+        let mut out = GenericArray::<Counted, U5>::uninit();
+        arr![Counted; Counted(1, &drop_counter), Counted(2, &drop_counter)].concat_into(
+            arr![Counted; Counted(3, &drop_counter), Counted(4, &drop_counter),
+                          Counted(5, &drop_counter)],
+            &mut out,
+        );
+        assert_eq!(drop_counter.get(), 0);
+
+        let joined = unsafe { GenericArray::assume_init(out) };
+        let values: GenericArray<i32, U5> = joined.map_ref(|c| c.0);
+        assert_eq!(values, arr![i32; 1, 2, 3, 4, 5]);
+    }
+    assert_eq!(drop_counter.get(), 5);
+}
+
+#[test]
+fn test_iter_rev() {
+    fn last_matching<T: PartialEq, N: generic_array::ArrayLength<T>>(
+        a: &GenericArray<T, N>,
+        value: &T,
+    ) -> Option<usize> {
+        a.iter_rev()
+            .position(|x| x == value)
+            .map(|i| N::to_usize() - 1 - i)
+    }
+
+    let mut a = arr![i32; 1, 2, 3, 2];
+    assert_eq!(
+        a.iter_rev().cloned().collect::<GenericArray<i32, U4>>(),
+        arr![i32; 2, 3, 2, 1]
+    );
+    assert_eq!(last_matching(&a, &2), Some(3));
+    assert_eq!(last_matching(&a, &5), None);
+
+    for (x, i) in a.iter_rev_mut().zip(0..) {
+        *x += i * 10;
+    }
+    assert_eq!(a, arr![i32; 31, 22, 13, 2]);
+}
+
+#[test]
+fn test_split_parity() {
+    use generic_array::typenum::{U0, U2, U5};
+
+    let (even, odd) = arr![i32; 0, 1, 2, 3].split_parity();
+    assert_eq!(even, arr![i32; 0, 2]);
+    assert_eq!(odd, arr![i32; 1, 3]);
+
+    let drop_counter = Cell::new(0);
+    {
+        let a: GenericArray<Counted, U5> =
+            GenericArray::generate(|i| Counted(i as i32, &drop_counter));
+        let (even, odd) = a.split_parity();
+        assert_eq!(drop_counter.get(), 0);
+
+        let even: GenericArray<i32, U3> = even.map(|c| c.0);
+        let odd: GenericArray<i32, U2> = odd.map(|c| c.0);
+        assert_eq!(even, arr![i32; 0, 2, 4]);
+        assert_eq!(odd, arr![i32; 1, 3]);
+    }
+    assert_eq!(drop_counter.get(), 5);
+
+    let (even, odd) = GenericArray::<i32, U0>::default().split_parity();
+    assert_eq!((even.len(), odd.len()), (0, 0));
+}
+
+#[test]
+fn test_write_sub() {
+    use generic_array::typenum::U2;
+
+    let mut a = arr![i32; 1, 2, 3, 4, 5];
+    assert_eq!(a.write_sub(1, arr![i32; 20, 30]), Ok(()));
+    assert_eq!(a, arr![i32; 1, 20, 30, 4, 5]);
+
+    assert_eq!(a.write_sub(3, arr![i32; 40, 50]), Ok(()));
+    assert_eq!(a, arr![i32; 1, 20, 30, 40, 50]);
+
+    assert_eq!(a.write_sub(4, arr![i32; 6, 7]), Err(arr![i32; 6, 7]));
+    assert_eq!(
+        a.write_sub(usize::MAX, arr![i32; 6, 7]),
+        Err(arr![i32; 6, 7])
+    );
+    assert_eq!(a, arr![i32; 1, 20, 30, 40, 50]);
+
+    let drop_counter = Cell::new(0);
+    {
+        let mut a: GenericArray<Counted, U4> =
+            GenericArray::generate(|i| Counted(i as i32, &drop_counter));
+        let value: GenericArray<Counted, U2> =
+            GenericArray::generate(|i| Counted(10 + i as i32, &drop_counter));
+        assert!(a.write_sub(2, value).is_ok());
+        assert_eq!(drop_counter.get(), 2);
+        assert_eq!((a[1].0, a[2].0, a[3].0), (1, 10, 11));
+
+        let value: GenericArray<Counted, U2> =
+            GenericArray::generate(|i| Counted(20 + i as i32, &drop_counter));
+        assert!(a.write_sub(3, value).is_err());
+        assert_eq!(drop_counter.get(), 4);
+    }
+    assert_eq!(drop_counter.get(), 8);
+}
+
+#[test]
+fn test_all_any() {
+    let a = arr![i32; 2, 4, 6];
+    assert!(a.all(|&x| x % 2 == 0));
+    assert!(a.any(|&x| x > 5));
+
+    assert!(!a.all(|&x| x > 10));
+    assert!(!a.any(|&x| x > 10));
+
+    let mixed = arr![i32; 1, 2, 3];
+    assert!(!mixed.all(|&x| x % 2 == 0));
+    assert!(mixed.any(|&x| x % 2 == 0));
+
+    let mut calls = 0;
+    assert!(mixed.any(|&x| {
+        calls += 1;
+        x == 1
+    }));
+    assert_eq!(calls, 1);
+
+    let empty = arr![i32;];
+    assert!(empty.all(|_| false));
+    assert!(!empty.any(|_| true));
+}
+
+#[test]
+fn test_as_str() {
+    assert_eq!(arr![u8; b'h', b'i'].as_str(), Ok("hi"));
+    assert_eq!(arr![u8; 0xc3, 0xa9, b't', 0xc3, 0xa9].as_str(), Ok("été"));
+
+    let err = arr![u8; b'o', b'k', 0xff, b'!'].as_str().unwrap_err();
+    assert_eq!(err.valid_up_to(), 2);
+
+    let err = arr![u8; b'a', 0xc3].as_str().unwrap_err();
+    assert_eq!(err.valid_up_to(), 1);
+    assert_eq!(err.error_len(), None);
+
+    assert_eq!(arr![u8;].as_str(), Ok(""));
+}
+
+#[test]
+fn test_from_iter_exact() {
+    use generic_array::ExactLenError;
+
+    assert_eq!(
+        GenericArray::<i32, U3>::from_iter_exact(1..4),
+        Ok(arr![i32; 1, 2, 3])
+    );
+
+    assert_eq!(
+        GenericArray::<i32, U3>::from_iter_exact(1..3),
+        Err(ExactLenError::TooFew {
+            got: 2,
+            expected: 3
+        })
+    );
+    assert_eq!(
+        GenericArray::<i32, U3>::from_iter_exact(1..),
+        Err(ExactLenError::TooMany { expected: 3 })
+    );
+
+    let drop_counter = Cell::new(0);
+
+    let result =
+        GenericArray::<Counted, U3>::from_iter_exact((0..2).map(|i| Counted(i, &drop_counter)));
+    assert!(result.is_err());
+    assert_eq!(drop_counter.get(), 2);
+
+    let result =
+        GenericArray::<Counted, U3>::from_iter_exact((0..5).map(|i| Counted(i, &drop_counter)));
+    assert!(result.is_err());
+    assert_eq!(drop_counter.get(), 6);
+}
+
+#[test]
+fn test_swizzle() {
+    use generic_array::swizzle::SwizzlePattern;
+
+    swizzle_pattern!(struct Dba for U4 = [3, 1, 0]);
+    swizzle_pattern!(struct Broadcast for U4 = [2, 2, 2, 2]);
+    swizzle_pattern!(struct Nothing for U4 = []);
+
+    let a = arr![char; 'a', 'b', 'c', 'd'];
+
+    assert_eq!(a.swizzle::<Dba>(), arr![char; 'd', 'b', 'a']);
+    assert_eq!(a.swizzle::<Broadcast>(), arr![char; 'c', 'c', 'c', 'c']);
+    assert_eq!(a.swizzle::<Nothing>(), arr![char;]);
+    assert_eq!(<Dba as SwizzlePattern<U4>>::index(1), 1);
+}
+
+#[test]
+#[should_panic(expected = "the len is 3 but the index is 3")]
+fn test_swizzle_out_of_bounds() {
+    use generic_array::swizzle::SwizzlePattern;
+    use generic_array::typenum::U2;
+
+    // `swizzle_pattern!` rejects this at compile time, a manual impl cannot.
+    struct TooFar;
+
+    impl SwizzlePattern<U3> for TooFar {
+        type Len = U2;
+
+        fn index(j: usize) -> usize {
+            [0, 3][j]
+        }
+    }
+
+    arr![i32; 1, 2, 3].swizzle::<TooFar>();
+}
+
+#[test]
+fn test_split3() {
+    use generic_array::typenum::{U0, U10};
+
+    let a: GenericArray<i32, U10> = GenericArray::generate(|i| i as i32);
+    let (head, body, tail) = a.split3::<U3, U4>();
+    assert_eq!(head, arr![i32; 0, 1, 2]);
+    assert_eq!(body, arr![i32; 3, 4, 5, 6]);
+    assert_eq!(tail, arr![i32; 7, 8, 9]);
+
+    let (head, body, tail) = arr![i32; 1, 2].split3::<U0, U0>();
+    assert_eq!((head.len(), body.len(), tail), (0, 0, arr![i32; 1, 2]));
+
+    let drop_counter = Cell::new(0);
+    {
+        let a: GenericArray<Counted, U10> =
+            GenericArray::generate(|i| Counted(i as i32, &drop_counter));
+        let (head, body, tail) = a.split3::<U3, U4>();
+        assert_eq!(drop_counter.get(), 0);
+        assert_eq!((head[2].0, body[0].0, tail[0].0), (2, 3, 7));
+    }
+    assert_eq!(drop_counter.get(), 10);
+}
+
+#[test]
+fn test_tile() {
+    use generic_array::typenum::{U0, U2, U6};
+
+    let tiled: GenericArray<i32, U6> = arr![i32; 1, 2].tile::<U3>();
+    assert_eq!(tiled, arr![i32; 1, 2, 1, 2, 1, 2]);
+
+    assert_eq!(GenericArray::tile::<U2>(&arr![i32; 7]), arr![i32; 7, 7]);
+    assert_eq!(arr![i32; 1, 2].tile::<U0>(), arr![i32;]);
+    assert_eq!(arr![i32;].tile::<U3>(), arr![i32;]);
+}
+
+#[test]
+fn test_diff() {
+    assert_eq!(arr![i32; 1, 3, 6, 10].diff(), arr![i32; 2, 3, 4]);
+    assert_eq!(arr![i32; 5, 5, 5].diff(), arr![i32; 0, 0]);
+    assert_eq!(arr![i32; 5].diff(), arr![i32;]);
+}
+
+#[test]
+fn test_cumsum() {
+    assert_eq!(arr![i32; 1, 2, 3, 4].cumsum(), arr![i32; 1, 3, 6, 10]);
+    assert_eq!(arr![i32; -7].cumsum(), arr![i32; -7]);
+    assert_eq!(arr![i32;].cumsum(), arr![i32;]);
+}
+
+#[test]
+fn test_clamp_each() {
+    assert_eq!(
+        arr![i32; -5, 0, 3, 7, 12].clamp_each(0, 7),
+        arr![i32; 0, 0, 3, 7, 7]
+    );
+    assert_eq!(arr![i32; 4, 4].clamp_each(4, 4), arr![i32; 4, 4]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "clamp_each: lo is greater than hi")]
+fn test_clamp_each_inverted_range() {
+    arr![i32; 1, 2].clamp_each(3, 1);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_normalized() {
+    let v = arr![f64; 3.0, 0.0, 4.0].normalized().unwrap();
+    assert_eq!(v, arr![f64; 0.6, 0.0, 0.8]);
+
+    let w = arr![f32; 1.0, -2.0, 3.0, 0.5].normalized().unwrap();
+    let norm_sq: f32 = w.iter().map(|x| x * x).sum();
+    assert!(norm_sq > 1.0 - 1e-6 && norm_sq < 1.0 + 1e-6);
+    assert!(w[1] < 0.0);
+
+    assert_eq!(arr![f64; 0.0, 0.0].normalized(), None);
+    assert_eq!(arr![f32;].normalized(), None);
+}
+
+#[test]
+fn test_swap_bytes_each() {
+    let a = arr![u32; 0x1122_3344, 0xaabb_ccdd];
+    let swapped = a.swap_bytes_each();
+    assert_eq!(swapped, arr![u32; 0x4433_2211, 0xddcc_bbaa]);
+    assert_eq!(swapped.swap_bytes_each(), a);
+
+    assert_eq!(arr![u8; 1, 2, 3].swap_bytes_each(), arr![u8; 1, 2, 3]);
+    assert_eq!(arr![u8; 1, 2, 3].to_be(), arr![u8; 1, 2, 3]);
+
+    let be = a.to_be();
+    assert_eq!(be[0].to_ne_bytes(), [0x11, 0x22, 0x33, 0x44]);
+    assert_eq!(be.from_be(), a);
+
+    let le = a.to_le();
+    assert_eq!(le[1].to_ne_bytes(), [0xdd, 0xcc, 0xbb, 0xaa]);
+    assert_eq!(le.from_le(), a);
+}
+
+#[test]
+fn test_count() {
+    let a = arr![i32; 1, 2, 3, 4, 6];
+    assert_eq!(a.count(|&x| x % 2 == 0), 3);
+    assert_eq!(a.count(|&x| x > 0), 5);
+    assert_eq!(a.count(|&x| x > 10), 0);
+    assert_eq!(arr![i32;].count(|_| true), 0);
+}
+
+#[test]
+fn test_max_min_by_key() {
+    let words = arr![&str; "bb", "a", "ccc", "ddd", "e"];
+
+    assert_eq!(words.max_by_key(|w| w.len()), Some(&"ccc"));
+    assert_eq!(words.min_by_key(|w| w.len()), Some(&"a"));
+
+    let single = arr![&str; "only"];
+    assert_eq!(single.max_by_key(|w| w.len()), Some(&"only"));
+    assert_eq!(single.min_by_key(|w| w.len()), Some(&"only"));
+
+    let empty = arr![&str;];
+    assert_eq!(empty.max_by_key(|w| w.len()), None);
+    assert_eq!(empty.min_by_key(|w| w.len()), None);
+}
+
+#[test]
+fn test_chunk_fold() {
+    use generic_array::typenum::{U2, U6};
+
+    let a = arr![i32; 2, 4, 10, 20, -3, 3];
+    assert_eq!(
+        a.chunk_fold::<U2, _, _>(|pair| (pair[0] + pair[1]) / 2),
+        arr![i32; 3, 15, 0]
+    );
+    assert_eq!(a.chunk_fold::<U6, _, _>(|all| all.len()), arr![usize; 6]);
+}
+
+#[test]
+fn test_be_bytes() {
+    use generic_array::typenum::{U2, U8};
+
+    let a = arr![u32; 0x0102_0304, 0xa0b0_c0d0];
+    let bytes: GenericArray<u8, U8> = a.to_be_bytes();
+    assert_eq!(bytes, arr![u8; 1, 2, 3, 4, 0xa0, 0xb0, 0xc0, 0xd0]);
+    assert_eq!(GenericArray::<u32, U2>::from_be_bytes(&bytes), a);
+
+    let b = arr![u64; 0x0102_0304_0506_0708];
+    assert_eq!(b.to_be_bytes(), arr![u8; 1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(GenericArray::<u64, U1>::from_be_bytes(&b.to_be_bytes()), b);
+
+    assert_eq!(arr![u32;].to_be_bytes(), arr![u8;]);
+}
+
+#[test]
+fn test_xor() {
+    let plain = arr![u8; b'h', b'e', b'l', b'l', b'o'];
+    let key = arr![u8; 0x00, 0xff, 0x5a, 0xa5, 0x13];
+
+    let cipher = plain.xor(&key);
+    assert_eq!(
+        cipher,
+        arr![u8; b'h', b'e' ^ 0xff, b'l' ^ 0x5a, b'l' ^ 0xa5, b'o' ^ 0x13]
+    );
+
+    let mut roundtrip = cipher;
+    roundtrip.xor_assign(&key);
+    assert_eq!(roundtrip, plain);
+}
+
+#[test]
+fn test_into_chunk_iter() {
+    use generic_array::typenum::{U2, U6};
+
+    let mut frames = arr![i32; 1, 2, 3, 4, 5, 6].into_chunk_iter::<U2>();
+    assert_eq!(frames.len(), 3);
+    assert_eq!(frames.next(), Some(arr![i32; 1, 2]));
+    assert_eq!(frames.next_back(), Some(arr![i32; 5, 6]));
+    assert_eq!(frames.next(), Some(arr![i32; 3, 4]));
+    assert_eq!(frames.next(), None);
+
+    let drop_counter = Cell::new(0);
+    {
+        let a: GenericArray<Counted, U6> =
+            GenericArray::generate(|i| Counted(i as i32, &drop_counter));
+        let mut frames = a.into_chunk_iter::<U3>();
+
+        let first = frames.next().unwrap();
+        assert_eq!(first[2].0, 2);
+        assert_eq!(drop_counter.get(), 0);
+
+        drop(frames);
+        assert_eq!(drop_counter.get(), 3);
+    }
+    assert_eq!(drop_counter.get(), 6);
+}
+
+#[test]
+fn test_merge() {
+    use core::ptr;
+    use generic_array::typenum::U5;
+
+    assert_eq!(
+        arr![i32; 1, 3, 5].merge(arr![i32; 2, 4, 6]),
+        arr![i32; 1, 2, 3, 4, 5, 6]
+    );
+    assert_eq!(arr![i32; 1, 2].merge(arr![i32;]), arr![i32; 1, 2]);
+    assert_eq!(arr![i32;].merge(arr![i32; 1, 2]), arr![i32; 1, 2]);
+    assert_eq!(
+        arr![i32; 7].merge(arr![i32; 1, 2, 3]),
+        arr![i32; 1, 2, 3, 7]
+    );
+
+    // Elements from each side get their own counter, which tells them apart
+    // when their values are equal.
+    let left_drops = Cell::new(0);
+    let right_drops = Cell::new(0);
+    {
+        let left = arr![Counted; Counted(1, &left_drops), Counted(2, &left_drops)];
+        let right = arr![Counted; Counted(0, &right_drops), Counted(2, &right_drops),
+                                  Counted(3, &right_drops)];
+
+        let merged: GenericArray<Counted, U5> = left.merge(right);
+        assert_eq!((left_drops.get(), right_drops.get()), (0, 0));
+
+        let order: GenericArray<(i32, bool), U5> =
+            merged.map_ref(|c| (c.0, ptr::eq(c.1, &left_drops)));
+        assert_eq!(
+            order,
+            arr![(i32, bool); (0, false), (1, true), (2, true), (2, false), (3, false)]
+        );
+    }
+    assert_eq!((left_drops.get(), right_drops.get()), (2, 3));
+}
+
+#[test]
+fn test_insertion_sort_small() {
+    use generic_array::ArrayLength;
+    use generic_array::typenum::{U0, U2, U5, U16, U17, U40};
+
+    fn check<N: ArrayLength<u8>>(seed: &mut u32) {
+        for _ in 0..200 {
+            let mut a: GenericArray<u8, N> = GenericArray::generate(|_| {
+                *seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                (*seed >> 24) as u8 % 8
+            });
+
+            let mut expected = a.clone();
+            expected.as_mut_slice().sort_unstable();
+
+            a.insertion_sort_small();
+            assert_eq!(a, expected);
+        }
+    }
+
+    let mut seed = 1;
+
+    check::<U0>(&mut seed);
+    check::<U1>(&mut seed);
+    check::<U2>(&mut seed);
+    check::<U5>(&mut seed);
+    check::<U16>(&mut seed);
+    check::<U17>(&mut seed);
+    check::<U40>(&mut seed);
+
+    // Up to 16 elements the sort is stable: the second counter tags which of
+    // two equal values came first.
+    let first = Cell::new(0);
+    let second = Cell::new(0);
+    {
+        let mut a = arr![Counted; Counted(2, &first), Counted(1, &first), Counted(2, &second),
+                                  Counted(1, &second)];
+        a.insertion_sort_small();
+
+        let order: GenericArray<(i32, bool), U4> = a.map_ref(|c| (c.0, core::ptr::eq(c.1, &first)));
+        assert_eq!(
+            order,
+            arr![(i32, bool); (1, true), (1, false), (2, true), (2, false)]
+        );
+        assert_eq!((first.get(), second.get()), (0, 0));
+    }
+    assert_eq!((first.get(), second.get()), (2, 2));
+}
+
+#[test]
+fn test_ranks() {
+    assert_eq!(arr![i32; 30, 10, 20].ranks(), arr![usize; 2, 0, 1]);
+    assert_eq!(arr![i32; 1, 2, 3, 4].ranks(), arr![usize; 0, 1, 2, 3]);
+    assert_eq!(arr![i32; 5, 1, 5, 1].ranks(), arr![usize; 2, 0, 3, 1]);
+    assert_eq!(arr![i32;].ranks(), arr![usize;]);
+}
+
+#[test]
+fn test_apply_lut() {
+    use generic_array::typenum::U256;
+
+    let identity: GenericArray<u8, U256> = GenericArray::generate(|i| i as u8);
+    let reversal: GenericArray<u8, U256> = GenericArray::generate(|i| 255 - i as u8);
+    let wide: GenericArray<u16, U256> = GenericArray::generate(|i| (i * 100) as u16);
+
+    let a = arr![u8; 0, 1, 127, 200, 255];
+    assert_eq!(a.apply_lut(&identity), a);
+    assert_eq!(a.apply_lut(&reversal), arr![u8; 255, 254, 128, 55, 0]);
+    assert_eq!(a.apply_lut(&wide), arr![u16; 0, 100, 12700, 20000, 25500]);
+}
+
+#[test]
+fn test_bit_reverse_permute() {
+    use generic_array::typenum::U2;
+
+    assert_eq!(
+        arr![i32; 0, 1, 2, 3, 4, 5, 6, 7].bit_reverse_permute(),
+        arr![i32; 0, 4, 2, 6, 1, 5, 3, 7]
+    );
+    assert_eq!(
+        arr![i32; 0, 1, 2, 3].bit_reverse_permute(),
+        arr![i32; 0, 2, 1, 3]
+    );
+    assert_eq!(
+        GenericArray::<i32, U2>::generate(|i| i as i32).bit_reverse_permute(),
+        arr![i32; 0, 1]
+    );
+    assert_eq!(arr![i32; 9].bit_reverse_permute(), arr![i32; 9]);
+}
+
+#[test]
+fn test_running_max() {
+    let a = arr![i32; 3, 1, 4, 1, 5, 9, 2, 6];
+
+    assert_eq!(a.running_max(1), a);
+    assert_eq!(a.running_max(8), arr![i32; 3, 3, 4, 4, 5, 9, 9, 9]);
+    assert_eq!(a.running_max(100), arr![i32; 3, 3, 4, 4, 5, 9, 9, 9]);
+    assert_eq!(a.running_max(3), arr![i32; 3, 3, 4, 4, 5, 9, 9, 9]);
+    assert_eq!(a.running_max(2), arr![i32; 3, 3, 4, 4, 5, 9, 9, 6]);
+    assert_eq!(arr![i32;].running_max(2), arr![i32;]);
+}
+
+#[test]
+#[should_panic(expected = "running_max window must not be zero")]
+fn test_running_max_zero_window() {
+    arr![i32; 1, 2].running_max(0);
+}
+
+#[test]
+fn test_same_length() {
+    use generic_array::{ArrayLength, SameLength};
+
+    fn dot<N, M>(a: &GenericArray<i32, N>, b: &GenericArray<i32, M>) -> i32
+    where
+        N: ArrayLength<i32>,
+        M: ArrayLength<i32>,
+        (): SameLength<N, M>,
+    {
+        a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+    }
+
+    GenericArray::assert_same_length(&arr![i32; 1, 2], &arr![char; 'a', 'b']);
+    GenericArray::assert_same_length(&arr![i32;], &arr![u8;]);
+
+    assert_eq!(dot(&arr![i32; 1, 2, 3], &arr![i32; 4, 5, 6]), 32);
+}
+
+#[test]
+fn test_into_tuple() {
+    use generic_array::tuple::ToTuple;
+    use generic_array::typenum::U12;
+
+    assert_eq!(arr![u8; 1, 2, 3].into_tuple(), (1u8, 2u8, 3u8));
+    assert_eq!(arr![char; 'x'].into_tuple(), ('x',));
+
+    let long: GenericArray<usize, U12> = GenericArray::generate(|i| i);
+    assert_eq!(long.into_tuple().11, 11);
+
+    let drop_counter = Cell::new(0);
+    {
+        let a = arr![Counted; Counted(1, &drop_counter), Counted(2, &drop_counter),
+                              Counted(3, &drop_counter)];
+        let (x, y, z) = a.into_tuple();
+        assert_eq!(drop_counter.get(), 0);
+        assert_eq!((x.0, y.0, z.0), (1, 2, 3));
+    }
+    assert_eq!(drop_counter.get(), 3);
+}
+
+#[test]
+fn test_from_tuple() {
+    use generic_array::tuple::{FromTuple, ToTuple};
+
+    let a = GenericArray::from_tuple((1, 2, 3, 4));
+    assert_eq!(a, arr![i32; 1, 2, 3, 4]);
+    assert_eq!(a.into_tuple(), (1, 2, 3, 4));
+
+    let single: GenericArray<&str, U1> = GenericArray::from_tuple(("one",));
+    assert_eq!(single, arr![&str; "one"]);
+
+    let drop_counter = Cell::new(0);
+    {
+        let a = GenericArray::from_tuple((Counted(1, &drop_counter), Counted(2, &drop_counter)));
+        let (x, y) = a.into_tuple();
+        assert_eq!(drop_counter.get(), 0);
+        assert_eq!((x.0, y.0), (1, 2));
+    }
+    assert_eq!(drop_counter.get(), 2);
+}
+
+#[test]
+fn test_find_byte() {
+    use generic_array::typenum::U4096;
+
+    let a = arr![u8; b'k', b'e', b'y', b'=', b'v', b'a', b'l', b'='];
+    assert_eq!(a.find_byte(b'k'), Some(0));
+    assert_eq!(a.find_byte(b'='), Some(3));
+    assert_eq!(a.find_byte(b'l'), Some(6));
+    assert_eq!(a.find_byte(b'\n'), None);
+    assert_eq!(arr![u8;].find_byte(0), None);
+
+    let mut long: GenericArray<u8, U4096> = GenericArray::default();
+    assert_eq!(long.find_byte(1), None);
+    long[4095] = 1;
+    assert_eq!(long.find_byte(1), Some(4095));
+}
+
+#[test]
+fn test_split_first_chunk() {
+    use generic_array::typenum::{U0, U2, U5};
+
+    let a = arr![i32; 1, 2, 3, 4];
+
+    let (head, tail) = a.split_first_chunk::<U2>().unwrap();
+    assert_eq!((head, tail), (&arr![i32; 1, 2], &[3, 4][..]));
+
+    let (head, tail) = a.split_first_chunk::<U4>().unwrap();
+    assert_eq!(head, &a);
+    assert!(tail.is_empty());
+
+    let (head, tail) = a.split_first_chunk::<U0>().unwrap();
+    assert_eq!((head.len(), tail), (0, &a[..]));
+
+    assert!(a.split_first_chunk::<U5>().is_none());
+}
+
+#[test]
+fn test_insert() {
+    let a = arr![i32; 1, 2, 3];
+    assert_eq!(a.insert(0, 0).ok(), Some(arr![i32; 0, 1, 2, 3]));
+    assert_eq!(a.insert(1, 9).ok(), Some(arr![i32; 1, 9, 2, 3]));
+    assert_eq!(a.insert(3, 4).ok(), Some(arr![i32; 1, 2, 3, 4]));
+    assert_eq!(arr![i32;].insert(0, 7).ok(), Some(arr![i32; 7]));
+
+    match a.insert(4, 5) {
+        Err((array, value)) => assert_eq!((array, value), (a, 5)),
+        Ok(longer) => panic!("inserted out of range: {:?}", longer),
+    }
+
+    let drop_counter = Cell::new(0);
+    {
+        let a: GenericArray<Counted, U3> =
+            GenericArray::generate(|i| Counted(i as i32, &drop_counter));
+        let longer = a.insert(1, Counted(10, &drop_counter)).ok().unwrap();
+        assert_eq!(drop_counter.get(), 0);
+        let values: GenericArray<i32, U4> = longer.map_ref(|c| c.0);
+        assert_eq!(values, arr![i32; 0, 10, 1, 2]);
+
+        let a: GenericArray<Counted, U3> =
+            GenericArray::generate(|i| Counted(i as i32, &drop_counter));
+        let (array, value) = a.insert(5, Counted(10, &drop_counter)).err().unwrap();
+        assert_eq!(drop_counter.get(), 0);
+        assert_eq!((array[2].0, value.0), (2, 10));
+    }
+    assert_eq!(drop_counter.get(), 8);
+}
+
+#[test]
+fn test_remove() {
+    let a = arr![i32; 1, 2, 3, 4];
+    assert_eq!(a.remove(0).ok(), Some((arr![i32; 2, 3, 4], 1)));
+    assert_eq!(a.remove(2).ok(), Some((arr![i32; 1, 2, 4], 3)));
+    assert_eq!(a.remove(3).ok(), Some((arr![i32; 1, 2, 3], 4)));
+    assert_eq!(arr![i32; 7].remove(0).ok(), Some((arr![i32;], 7)));
+    assert_eq!(a.remove(4).err(), Some(a));
+
+    let drop_counter = Cell::new(0);
+    {
+        let a: GenericArray<Counted, U4> =
+            GenericArray::generate(|i| Counted(i as i32, &drop_counter));
+        let (kept, removed) = a.remove(1).ok().unwrap();
+        assert_eq!(drop_counter.get(), 0);
+        let values: GenericArray<i32, U3> = kept.map_ref(|c| c.0);
+        assert_eq!((values, removed.0), (arr![i32; 0, 2, 3], 1));
+
+        drop(removed);
+        assert_eq!(drop_counter.get(), 1);
+        drop(kept);
+        assert_eq!(drop_counter.get(), 4);
+
+        let a: GenericArray<Counted, U3> =
+            GenericArray::generate(|i| Counted(i as i32, &drop_counter));
+        let a = a.remove(3).err().unwrap();
+        assert_eq!(drop_counter.get(), 4);
+        assert_eq!(a[2].0, 2);
+    }
+    assert_eq!(drop_counter.get(), 7);
+}
+
+#[test]
+fn test_swap_const() {
+    use generic_array::typenum::{U0, U2};
+
+    let mut a = arr![i32; 1, 2, 3, 4];
+    a.swap_const::<U0, U3>();
+    assert_eq!(a, arr![i32; 4, 2, 3, 1]);
+
+    a.swap_const::<U2, U1>();
+    assert_eq!(a, arr![i32; 4, 3, 2, 1]);
+
+    a.swap_const::<U2, U2>();
+    assert_eq!(a, arr![i32; 4, 3, 2, 1]);
+}
+
+#[test]
+fn test_sorting_network() {
+    let mut permutations = 0;
+
+    for a in 0..4 {
+        for b in (0..4).filter(|&b| b != a) {
+            for c in (0..4).filter(|&c| c != a && c != b) {
+                let d = 6 - a - b - c;
+                let mut array = arr![i32; a, b, c, d];
+
+                sorting_network!(array; (U0, U1), (U2, U3), (U0, U2), (U1, U3), (U1, U2));
+
+                assert_eq!(array, arr![i32; 0, 1, 2, 3]);
+                permutations += 1;
+            }
+        }
+    }
+
+    assert_eq!(permutations, 24);
+
+    let mut words = arr![&str; "pear", "apple"];
+    sorting_network!(words; (U0, U1));
+    assert_eq!(words, arr![&str; "apple", "pear"]);
+}
+
+#[test]
+fn test_rolling_hashes() {
+    use generic_array::typenum::{U0, U8};
+
+    fn fresh_hash(window: &[u8], base: u64, modulus: u64) -> u64 {
+        let (base, modulus) = (u128::from(base), u128::from(modulus));
+
+        window
+            .iter()
+            .fold(0, |acc, &b| (acc * base + u128::from(b)) % modulus) as u64
+    }
+
+    let text = arr![u8; b'a', b'b', b'r', b'a', b'c', b'a', b'd', b'a', b'b', b'r', b'a'];
+
+    for &(base, modulus) in &[(256, 1_000_000_007), (31, 101), (257, 1)] {
+        let hashes = text.rolling_hashes::<U3>(base, modulus);
+        assert_eq!(hashes.len(), 9);
+
+        for (i, &hash) in hashes.iter().enumerate() {
+            assert_eq!(hash, fresh_hash(&text[i..i + 3], base, modulus));
+        }
+
+        assert_eq!(hashes[0], hashes[7]);
+    }
+
+    let large = text.rolling_hashes::<U8>(u64::MAX - 1, u64::MAX);
+    for (i, &hash) in large.iter().enumerate() {
+        assert_eq!(hash, fresh_hash(&text[i..i + 8], u64::MAX - 1, u64::MAX));
+    }
+
+    let empty_windows = text.rolling_hashes::<U0>(256, 101);
+    assert_eq!(empty_windows.len(), 12);
+    assert!(empty_windows.iter().all(|&h| h == 0));
+}
+
+#[test]
+fn test_saturating_cast() {
+    use generic_array::SaturatingFrom;
+
+    let a = arr![i32; -1000, -1, 0, 7, 255, 256, 100_000];
+    assert_eq!(
+        a.saturating_cast::<u8>(),
+        arr![u8; 0, 0, 0, 7, 255, 255, 255]
+    );
+    assert_eq!(
+        a.saturating_cast::<i8>(),
+        arr![i8; -128, -1, 0, 7, 127, 127, 127]
+    );
+    assert_eq!(
+        a.saturating_cast::<i64>(),
+        arr![i64; -1000, -1, 0, 7, 255, 256, 100_000]
+    );
+
+    assert_eq!(
+        arr![u64; u64::MAX, 3].saturating_cast::<i32>(),
+        arr![i32; i32::MAX, 3]
+    );
+    assert_eq!(
+        arr![i128; i128::MIN].saturating_cast::<u128>(),
+        arr![u128; 0]
+    );
+    assert_eq!(u16::saturating_from(-5i8), 0);
+}
@@ -0,0 +1,123 @@
+#[macro_use]
+extern crate generic_array;
+extern crate typenum;
+
+use generic_array::GenericArray;
+use std::cell::Cell;
+use std::panic;
+use std::cmp::Ordering;
+use typenum::{U2, U3, U5, U6};
+
+/// Drop-counted value whose `clone` panics for the value `3`.
+struct PanicOnClone<'a>(usize, &'a Cell<usize>);
+
+impl<'a> Clone for PanicOnClone<'a> {
+    fn clone(&self) -> Self {
+        if self.0 == 3 {
+            panic!("clone of 3");
+        }
+        PanicOnClone(self.0, self.1)
+    }
+}
+
+impl<'a> Drop for PanicOnClone<'a> {
+    fn drop(&mut self) {
+        self.1.set(self.1.get() + 1);
+    }
+}
+
+#[test]
+fn test_tile_panicking_clone() {
+    let drop_counter = Cell::new(0);
+
+    let pattern =
+        arr![PanicOnClone; PanicOnClone(1, &drop_counter), PanicOnClone(3, &drop_counter)];
+
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| pattern.tile::<U3>()));
+
+    assert!(result.is_err());
+
+    // Only the clone of the first element was made before the panic.
+    assert_eq!(drop_counter.get(), 1);
+
+    let pattern: GenericArray<PanicOnClone, U2> = pattern;
+
+    drop(pattern);
+
+    assert_eq!(drop_counter.get(), 3);
+}
+
+#[test]
+fn test_windows_collect_panicking_clone() {
+    let drop_counter = Cell::new(0);
+
+    let a: GenericArray<PanicOnClone, U5> =
+        GenericArray::generate(|i| PanicOnClone(i, &drop_counter));
+
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| a.windows_collect::<U3>()));
+
+    assert!(result.is_err());
+
+    // The first window `[0, 1, 2]` and the leading `[1, 2]` of the second
+    // were cloned before the panic, and must have been dropped.
+    assert_eq!(drop_counter.get(), 5);
+
+    drop(a);
+
+    assert_eq!(drop_counter.get(), 10);
+}
+
+/// Drop-counted value whose comparisons panic once the shared budget runs out.
+struct PanicOnCmp<'a>(usize, &'a Cell<usize>, &'a Cell<usize>);
+
+impl<'a> PartialEq for PanicOnCmp<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for PanicOnCmp<'a> {}
+
+impl<'a> PartialOrd for PanicOnCmp<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for PanicOnCmp<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.2.get() == 0 {
+            panic!("out of comparisons");
+        }
+        self.2.set(self.2.get() - 1);
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<'a> Drop for PanicOnCmp<'a> {
+    fn drop(&mut self) {
+        self.1.set(self.1.get() + 1);
+    }
+}
+
+#[test]
+fn test_insertion_sort_small_panicking_cmp() {
+    let drop_counter = Cell::new(0);
+    let budget = Cell::new(7);
+
+    let mut a: GenericArray<PanicOnCmp, U6> =
+        GenericArray::generate(|i| PanicOnCmp(5 - i, &drop_counter, &budget));
+
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| a.insertion_sort_small()));
+
+    assert!(result.is_err());
+    assert_eq!(drop_counter.get(), 0);
+
+    let mut values: Vec<usize> = a.iter().map(|x| x.0).collect();
+    values.sort();
+    assert_eq!(values, [0, 1, 2, 3, 4, 5]);
+
+    drop(a);
+
+    assert_eq!(drop_counter.get(), 6);
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "rayon")]
+
+#[macro_use]
+extern crate generic_array;
+extern crate typenum;
+
+use generic_array::GenericArray;
+use typenum::U4096;
+
+#[test]
+fn par_reduce() {
+    let a: GenericArray<u64, U4096> = GenericArray::generate(|i| i as u64 * 7 + 3);
+    let sequential = a.iter().sum::<u64>();
+
+    assert_eq!(a.par_reduce(0, |x, y| x + y), sequential);
+
+    let words: GenericArray<String, U4096> = GenericArray::generate(|i| (i % 10).to_string());
+    let concatenated: String = words.iter().map(|s| s.as_str()).collect();
+
+    assert_eq!(
+        words.par_reduce(String::new(), |mut x, y| {
+            x.push_str(&y);
+            x
+        }),
+        concatenated
+    );
+}
+
+#[test]
+fn par_reduce_empty() {
+    assert_eq!(arr![u64;].par_reduce(42, |x, y| x + y), 42);
+    assert_eq!(arr![u64; 5].par_reduce(0, |x, y| x + y), 5);
+}